@@ -0,0 +1,20 @@
+extern crate reachability_solver;
+
+use reachability_solver::agents;
+
+fn main() {
+    // Harry starts at `1` and Cedric starts at `2`.
+    // Both try to reach the Triwizard Cup at `5`.
+    let harry_and_cedric = vec![[1, 5], [2, 5]];
+
+    let answers = agents::solve(&[[1, 4], [2, 3], [3, 4], [4, 5]], &harry_and_cedric);
+    println!("{:?} {}", answers, agents::all_reach(&answers));
+
+    // In an alternative timeline, Harry does not help Cedric.
+    let answers = agents::solve(&[[1, 4], [2, 3], [4, 5]], &harry_and_cedric);
+    println!("{:?} {}", answers, agents::all_reach(&answers));
+
+    // Starting in the middle of the maze can not be answered from the solution.
+    let answers = agents::solve(&[[1, 4], [2, 3], [3, 4], [4, 5]], &[[3, 5], [1, 4], [1, 6]]);
+    println!("{:?} {}", answers, agents::all_reach(&answers));
+}
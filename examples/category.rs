@@ -0,0 +1,24 @@
+extern crate reachability_solver;
+
+use reachability_solver::category::Category;
+
+fn main() {
+    //   2
+    // 4 5 6
+    //   8
+    let c = Category::new(&[[2, 5], [4, 5], [5, 6], [5, 8]]);
+    println!("objects: {:?}", c.objects());
+    println!("identity of 5: {:?}", c.identity(5));
+    println!("hom(2, 6): {:?}", c.hom(2, 6));
+    println!("hom(6, 2): {:?}", c.hom(6, 2));
+    println!("[2, 5] ; [5, 8]: {:?}", c.compose([2, 5], [5, 8]));
+    println!("initial: {:?}, sources: {:?}", c.initial_objects(), c.sources());
+    println!("terminal: {:?}, sinks: {:?}", c.terminal_objects(), c.sinks());
+    println!("{:?}\n", c.disagreements());
+
+    // 1 → 2 ⇄ 3
+    let c = Category::new(&[[1, 2], [2, 3], [3, 2]]);
+    println!("initial: {:?}, sources: {:?}", c.initial_objects(), c.sources());
+    println!("terminal: {:?}, sinks: {:?}", c.terminal_objects(), c.sinks());
+    println!("{:?}", c.disagreements());
+}
@@ -0,0 +1,16 @@
+extern crate reachability_solver;
+
+use reachability_solver::{solve, closure};
+
+fn main() {
+    // 2 → 3 → 4
+    let x = vec![[2, 3], [3, 4]];
+    println!("{:?}", solve(x.clone()));
+    println!("{:?}", closure::closure(&x));
+    println!("{:?}", closure::reflexive_closure(&x));
+
+    let m = closure::Matrix::new(&x);
+    println!("3 reaches 4: {}", m.reaches(3, 4));
+    println!("4 reaches 3: {}", m.reaches(4, 3));
+    println!("3 reaches 3: {}", m.reaches(3, 3));
+}
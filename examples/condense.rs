@@ -0,0 +1,26 @@
+extern crate reachability_solver;
+
+use reachability_solver::scc;
+
+fn main() {
+    // Every node in the loop is reachable from `1` and `3`,
+    // but the loop contains no terminal node.
+    //
+    // 1     3
+    // |     |
+    // 4--5--6
+    // |     |
+    // 7     9
+    // |     |
+    // 10-11-12
+    //
+    let c = scc::condense(&[
+        [1, 4], [3, 6],
+        [5, 6], [6, 9], [9, 12], [12, 11], [11, 10], [10, 7], [7, 4], [4, 5]
+    ]);
+    println!("{:?}", c.components);
+    println!("{:?}", c.edges);
+    for [a, b] in c.solve() {
+        println!("{:?} -> {:?}", c.components[a], c.components[b]);
+    }
+}
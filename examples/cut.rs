@@ -0,0 +1,32 @@
+extern crate reachability_solver;
+
+use reachability_solver::{cut, shapes};
+
+fn main() {
+    //     2
+    //   ↗︎   ↘︎
+    // 1       4 → 5
+    //   ↘︎   ↗︎
+    //     3
+    let x = vec![[1, 2], [1, 3], [2, 4], [3, 4], [4, 5]];
+    println!("{:?}", cut::edge_cut(&x, 1, 5));
+    println!("{:?}", cut::node_cut(&x, 1, 5));
+
+    // The edge `[4, 5]` and the node `4` cut both initial nodes off from `5`.
+    //
+    // 1 → 4 → 5
+    //     ↑
+    // 2 → 3
+    let x = vec![[1, 4], [2, 3], [3, 4], [4, 5]];
+    println!("{:?}", cut::edge_cut_all(&x));
+    println!("{:?}", cut::node_cut_all(&x));
+
+    // 0 → 1 → 2
+    // ↓   ↓   ↓
+    // 3 → 4 → 5
+    // ↓   ↓   ↓
+    // 6 → 7 → 8
+    let x = shapes::diag_rect([3, 3]);
+    println!("{:?}", cut::edge_cut(&x, 0, 8));
+    println!("{:?}", cut::node_cut(&x, 0, 8));
+}
@@ -0,0 +1,31 @@
+extern crate reachability_solver;
+
+use reachability_solver::{disjoint, shapes};
+
+fn main() {
+    //     2       5
+    //   ↗︎   ↘︎   ↗︎   ↘︎
+    // 1       4       7
+    //   ↘︎   ↗︎   ↘︎   ↗︎
+    //     3       6
+    let x = vec![[1, 2], [1, 3], [2, 4], [3, 4], [4, 5], [4, 6], [5, 7], [6, 7]];
+    println!("{:?}", disjoint::edge_disjoint(&x, 1, 7));
+    println!("{:?}", disjoint::node_disjoint(&x, 1, 7));
+
+    // Each initial node has a single path to `5`.
+    //
+    // 1 → 4 → 5
+    //     ↑
+    // 2 → 3
+    let x = vec![[1, 4], [2, 3], [3, 4], [4, 5]];
+    println!("{:?}", disjoint::edge_disjoint_all(&x));
+
+    // 0 → 1 → 2
+    // ↓   ↓   ↓
+    // 3 → 4 → 5
+    // ↓   ↓   ↓
+    // 6 → 7 → 8
+    let x = shapes::diag_rect([3, 3]);
+    println!("{:?}", disjoint::edge_disjoint_all(&x));
+    println!("{:?}", disjoint::node_disjoint_all(&x));
+}
@@ -0,0 +1,33 @@
+extern crate reachability_solver;
+
+use reachability_solver::{dominators, shapes};
+
+fn main() {
+    // Every path from `1` or `2` to `5` passes through `3` and `4`.
+    //
+    // 1
+    //  ↘︎
+    //    3 → 4 → 5
+    //  ↗︎
+    // 2
+    let x = vec![[1, 3], [2, 3], [3, 4], [4, 5]];
+    for w in dominators::waypoints(&x) {
+        println!("{:?}", w);
+    }
+
+    // There are many paths through a rectangle.
+    //
+    // 0 → 1 → 2
+    // ↓   ↓   ↓
+    // 3 → 4 → 5
+    let x = shapes::diag_rect([3, 2]);
+    let mut tree: Vec<_> = dominators::dominator_tree(&x, 0).into_iter().collect();
+    tree.sort();
+    println!("{:?}", tree);
+    let mut tree: Vec<_> = dominators::post_dominator_tree(&x, 5).into_iter().collect();
+    tree.sort();
+    println!("{:?}", tree);
+    for w in dominators::waypoints(&x) {
+        println!("{:?}", w);
+    }
+}
@@ -0,0 +1,30 @@
+extern crate reachability_solver;
+
+use reachability_solver::{try_solve, etch, map, shapes};
+
+fn main() {
+    println!("{:?}", try_solve(vec![[1, 2], [2, 3]]));
+    for x in [
+        vec![],
+        vec![[1, 2], [2, 2]],
+        vec![[1, 2], [2, 3], [1, 2]],
+        vec![[1, 2], [2, 1], [2, 3]],
+    ] {
+        if let Err(err) = try_solve(x) {println!("{}", err)}
+    }
+
+    println!("{:?}", etch::try_cardinality(&shapes::diag_rect([3, 3])));
+    // 1 → 2 → 3 ⇄ 4
+    if let Err(err) = etch::try_cardinality(&[[1, 2], [2, 3], [3, 4], [4, 3]]) {
+        println!("{}", err);
+    }
+
+    println!("{}", map::visualize(&map::try_map2([3, 1], &shapes::dir_line(3)).unwrap()));
+    for (dim, x) in [
+        ([0, 3], vec![]),
+        ([3, 1], shapes::dir_line(4)),
+        ([3, 3], vec![[0, 2]]),
+    ] {
+        if let Err(err) = map::try_map2(dim, &x) {println!("{}", err)}
+    }
+}
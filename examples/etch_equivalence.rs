@@ -0,0 +1,26 @@
+extern crate reachability_solver;
+
+use reachability_solver::etch;
+
+fn main() {
+    // These mazes are different, but the shortcut `[1, 3]`
+    // can not be detected by initial etching.
+    //
+    // 1 → 2 → 3
+    let a = vec![[1, 2], [2, 3]];
+    // 1 → 2 → 3
+    //  \_____↗
+    let b = vec![[1, 2], [2, 3], [1, 3]];
+    // 1 → 2    3
+    let c = vec![[1, 2]];
+
+    println!("{:?}", etch::signature(&a));
+    println!("{:?}", etch::signature(&b));
+    println!("{:?}", etch::signature(&c));
+    assert_eq!(etch::signature(&a), etch::signature(&b));
+    assert_ne!(etch::signature(&a), etch::signature(&c));
+
+    let classes = etch::equivalence_classes(&[a, b, c]);
+    println!("{:?}", classes);
+    assert_eq!(classes, vec![vec![0, 1], vec![2]]);
+}
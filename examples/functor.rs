@@ -0,0 +1,26 @@
+extern crate reachability_solver;
+
+use reachability_solver::{functor, shapes};
+
+fn main() {
+    // 0 → 1 → 2 → 3
+    let a = shapes::dir_line(4);
+    // 0 → 1 → 2
+    let b = shapes::dir_line(3);
+
+    // Collapses the middle of the line.
+    let f = |x: usize| [0, 1, 1, 2][x];
+    println!("{:?}", functor::check(&a, &b, f));
+
+    // Reverses the line, which breaks every edge.
+    let g = |x: usize| [2, 1, 1, 0][x];
+    println!("{:?}", functor::check(&a, &b, g));
+
+    // A line can not be embedded into a shorter line,
+    // but it can be embedded into a rectangle.
+    println!("{:?}", functor::search(&a, &b));
+    let mut f: Vec<_> = functor::search(&a, &shapes::diag_rect([2, 3]))
+        .unwrap().into_iter().collect();
+    f.sort();
+    println!("{:?}", f);
+}
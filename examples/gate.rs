@@ -0,0 +1,22 @@
+extern crate reachability_solver;
+
+use reachability_solver::gate;
+
+fn main() {
+    // Harry starts at `1` and Cedric starts at `2`.
+    // Both try to reach the Triwizard Cup at `5`,
+    // but the gate `[3, 4]` only lets the first person through.
+    //
+    // 1
+    //  ↘︎
+    //    3 ⇢ 4 → 5
+    //  ↗︎
+    // 2
+    let agents = vec![[1, 5], [2, 5]];
+    let report = gate::solve(&[[1, 3], [2, 3], [4, 5]], &[[3, 4]], &agents);
+    println!("{:?}", report);
+
+    // Harry finds another way around the gate.
+    let report = gate::solve(&[[1, 3], [2, 3], [4, 5], [1, 4]], &[[3, 4]], &agents);
+    println!("{:?}", report);
+}
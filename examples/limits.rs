@@ -0,0 +1,40 @@
+extern crate reachability_solver;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use reachability_solver::{shapes, Error};
+use reachability_solver::limits::{self, Options};
+
+fn main() {
+    let x = shapes::diag_rect([3, 3]);
+
+    // Without limits, the output is the same as `solve`.
+    let r = limits::solve(x.clone(), Options {
+        progress: Some(Box::new(|p| if p.steps % 10 == 0 {println!("{:?}", p)})),
+        ..Options::default()
+    });
+    println!("{:?}", r);
+
+    let r = limits::solve(x.clone(), Options {max_steps: Some(10), ..Options::default()});
+    if let Err(Error::Interrupted {reason, partial}) = r {
+        println!("{:?} {:?}", reason, partial);
+    }
+
+    let r = limits::solve(x.clone(), Options {
+        deadline: Some(Instant::now() + Duration::from_secs(10)),
+        ..Options::default()
+    });
+    println!("{:?}", r);
+
+    // Cancels the solver after it has propagated 5 facts.
+    let cancel = Arc::new(AtomicBool::new(false));
+    let flag = cancel.clone();
+    let r = limits::solve(x, Options {
+        cancel: Some(cancel),
+        progress: Some(Box::new(move |p| if p.propagated >= 5 {flag.store(true, Ordering::SeqCst)})),
+        ..Options::default()
+    });
+    if let Err(err) = r {println!("{}", err)}
+}
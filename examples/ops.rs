@@ -0,0 +1,13 @@
+extern crate reachability_solver;
+
+use reachability_solver::{solve, ops, shapes};
+
+fn main() {
+    // Two lines in sequence.
+    let x = ops::sequence(&shapes::dir_line(3), &shapes::dir_line(3));
+    println!("{:?}\n---------------------------------------\n{:?}", x, solve(x.clone()));
+
+    // A rectangle is the product of two lines.
+    let x = ops::product(&shapes::dir_line(3), &shapes::dir_line(2));
+    println!("{:?}\n---------------------------------------\n{:?}", x, solve(x.clone()));
+}
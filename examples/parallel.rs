@@ -0,0 +1,20 @@
+extern crate reachability_solver;
+
+use reachability_solver::{solve_sorted, ops, parallel, shapes};
+
+fn main() {
+    let mut x = vec![];
+    for n in 2..6 {
+        x = ops::coproduct(&x, &shapes::dir_line(n));
+        x = ops::coproduct(&x, &shapes::diag_rect([n, 2]));
+    }
+    println!("{} components", parallel::components(&x).len());
+
+    // The output is the same as `solve_sorted`, for any number of threads.
+    let r = parallel::solve(&x);
+    println!("{:?}", r);
+    for threads in 1..4 {
+        assert_eq!(parallel::solve_with_threads(&x, threads), r);
+    }
+    assert_eq!(solve_sorted(x), r);
+}
@@ -0,0 +1,21 @@
+extern crate reachability_solver;
+
+use reachability_solver::paths;
+
+fn main() {
+    // 1 → 2 → 3
+    // ↓   ↓   ↓
+    // 4 → 5 → 6
+    println!("{:?}", paths::count(&[
+        [1, 2], [2, 3], [1, 4], [2, 5], [3, 6], [4, 5], [5, 6]
+    ]));
+
+    // The path from `1` to `6` passes through the cycle `2 → 5 → 4 → 2`.
+    //
+    // 1 → 2
+    //     ↑ ↘︎
+    // 7   4 ← 5 → 6
+    println!("{:?}", paths::count(&[
+        [1, 2], [2, 5], [5, 4], [4, 2], [5, 6], [7, 4]
+    ]));
+}
@@ -0,0 +1,27 @@
+extern crate reachability_solver;
+
+use reachability_solver::{solve, reachable_from, reaches};
+
+fn main() {
+    // This example shows that interior nodes can be queried directly,
+    // even when they are not in the solution.
+    //
+    //    2
+    //    |
+    // 4--5--6
+    // |     |
+    // 7     9
+    // |     |
+    // 10-11-12
+    //
+    let x = vec![
+        [2, 5],
+        [5, 6], [6, 9], [9, 12], [12, 11], [11, 10], [10, 7], [7, 4], [4, 5]
+    ];
+    println!("{:?}", solve(x.clone()));
+    println!("{:?}", reachable_from(&x, 2));
+    println!("{:?}", reachable_from(&x, 9));
+    println!("9 reaches 4: {}", reaches(&x, 9, 4));
+    println!("9 reaches 2: {}", reaches(&x, 9, 2));
+    println!("9 reaches 9: {}", reaches(&x, 9, 9));
+}
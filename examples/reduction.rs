@@ -0,0 +1,29 @@
+extern crate reachability_solver;
+
+use reachability_solver::shapes;
+use reachability_solver::closure::{closure, transitive_reduction};
+
+fn show(x: &[[usize; 2]]) {
+    let y = transitive_reduction(x);
+    println!("{:?}\n---------------------------------------\n{:?}\n", x, y);
+}
+
+fn main() {
+    for n in 0..6 {
+        show(&shapes::dir_line(n));
+        show(&closure(&shapes::dir_line(n)));
+    }
+    for w in 1..4 {
+        for h in 1..4 {
+            let x = shapes::diag_rect([w, h]);
+            show(&x);
+            show(&closure(&x));
+        }
+    }
+
+    // Cycles are reduced to one cycle per strongly connected component.
+    //
+    // 1 ⇄ 2 → 3 ⇄ 4
+    //  \_______↗
+    show(&[[1, 2], [2, 1], [2, 3], [3, 4], [4, 3], [1, 3], [5, 5]]);
+}
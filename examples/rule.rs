@@ -0,0 +1,51 @@
+extern crate reachability_solver;
+
+use std::collections::{HashMap, HashSet};
+
+use reachability_solver::{solve_with, shapes};
+use reachability_solver::rule::{Inference, InferenceRule, Transitive};
+
+/// Propagates only paths within a budget of hops.
+struct HopBudget {
+    budget: usize,
+    hops: HashMap<[usize; 2], usize>,
+}
+
+impl InferenceRule for HopBudget {
+    fn infer(&mut self, cache: &HashSet<[usize; 2]>, facts: &[[usize; 2]]) -> Option<Inference<[usize; 2]>> {
+        for &[a, b] in facts {
+            for &[c, d] in facts {
+                if b != c || cache.contains(&[a, d]) {continue}
+                let h = self.hops.get(&[a, b]).unwrap_or(&1) + self.hops.get(&[c, d]).unwrap_or(&1);
+                if h <= self.budget {
+                    self.hops.insert([a, d], h);
+                    return Some(Inference::Propagate([a, d]));
+                }
+            }
+        }
+
+        let from = facts.iter().cloned()
+            .filter(|&[a, b]| facts.iter().any(|&[c, d]| c == b || a == d)).collect();
+        Some(Inference::ManyTrue {from})
+    }
+}
+
+fn main() {
+    // The built-in rule gives the same output as `solve`.
+    let x = shapes::diag_rect([3, 3]);
+    println!("{:?}", solve_with(x, Transitive));
+
+    // 0 → 1 → 2 → 3 → 4
+    let x = shapes::dir_line(5);
+    for budget in 1..6 {
+        let r = solve_with(x.clone(), HopBudget {budget, hops: HashMap::new()});
+        println!("budget {}: {:?}", budget, r);
+    }
+
+    // Closures can be used as rules.
+    // This rule only removes edges into `2`, which splits the line in two.
+    let r = solve_with(x, |_: &HashSet<[usize; 2]>, facts: &[[usize; 2]]| {
+        facts.iter().find(|e| e[1] == 2).map(|&e| Inference::OneTrue {from: e})
+    });
+    println!("{:?}", r);
+}
@@ -0,0 +1,24 @@
+extern crate reachability_solver;
+
+use reachability_solver::sat::{self, Expr};
+use reachability_solver::sat::Expr::*;
+
+fn main() {
+    // Harry starts at `1` and Cedric starts at `2`.
+    // Both try to reach the Triwizard Cup at `5`.
+    // The edge `[3, 4]` represents the moment when Harry helps Cedric.
+    let edges = vec![[1, 4], [2, 3], [4, 5]];
+    let optional = vec![[3, 4]];
+
+    // (Harry -> TriwizardCup) ∧ ¬(Cedric -> TriwizardCup)
+    let only_harry = And(Box::new(Reach(1, 5)), Box::new(Not(Box::new(Reach(2, 5)))));
+    println!("{:?}", sat::find(&edges, &optional, &only_harry));
+
+    // (Harry -> TriwizardCup) ∧ (Cedric -> TriwizardCup)
+    let both = And(Box::new(Reach(1, 5)), Box::new(Reach(2, 5)));
+    println!("{:?}", sat::find(&edges, &optional, &both));
+
+    // ¬(Harry -> TriwizardCup)
+    let not_harry = Expr::Not(Box::new(Reach(1, 5)));
+    println!("{:?}", sat::find(&edges, &optional, &not_harry));
+}
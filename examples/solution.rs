@@ -0,0 +1,23 @@
+extern crate reachability_solver;
+
+use reachability_solver::solution::Solution;
+
+fn main() {
+    // 1 → 4 → 5
+    //     ↑
+    // 2 → 3
+    let joined = Solution::new(vec![[1, 4], [2, 3], [3, 4], [4, 5]]);
+    // Without the edge `[3, 4]`, `2` no longer reaches `5`.
+    let split = Solution::new(vec![[1, 4], [2, 3], [4, 5]]);
+
+    println!("initials: {:?}", joined.initials());
+    println!("terminals: {:?}", joined.terminals());
+    println!("sources of 5: {:?}", joined.sources_of(5));
+    println!("targets of 2: {:?} vs {:?}", joined.targets_of(2), split.targets_of(2));
+    println!("2 reaches 5: {} vs {}", joined.contains(2, 5), split.contains(2, 5));
+
+    println!("union: {:?}", joined.union(&split).pairs());
+    println!("intersection: {:?}", joined.intersection(&split).pairs());
+    println!("lost: {:?}", joined.difference(&split).pairs());
+    println!("gained: {:?}", split.difference(&joined).pairs());
+}
@@ -0,0 +1,18 @@
+extern crate reachability_solver;
+
+use reachability_solver::{solve, solve_sorted};
+
+fn main() {
+    // 1 → 2 → 3
+    // ↓   ↓   ↓
+    // 4 → 5 → 6
+    let x = vec![[1, 2], [2, 3], [1, 4], [2, 5], [3, 6], [4, 5], [5, 6], [7, 6], [1, 8]];
+    let mut y = x.clone();
+    y.reverse();
+    y.push([2, 5]);
+
+    println!("{:?}", solve(x.clone()));
+    println!("{:?}", solve(y.clone()));
+    println!("{:?}", solve_sorted(x.clone()));
+    assert_eq!(solve_sorted(x), solve_sorted(y));
+}
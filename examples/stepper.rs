@@ -0,0 +1,20 @@
+extern crate reachability_solver;
+
+use reachability_solver::{shapes, solve};
+use reachability_solver::stepper::Stepper;
+
+fn main() {
+    // 0 → 1 → 2
+    // ↓   ↓   ↓
+    // 3 → 4 → 5
+    let dim = [3, 2];
+    let x = shapes::diag_rect(dim);
+    let mut s = Stepper::new(x.clone());
+    println!("{}\n", s.visualize(dim).unwrap());
+    while let Some(step) = s.step() {
+        println!("{}", step);
+        println!("{:?}", s.current_facts());
+        println!("{}\n", s.visualize(dim).unwrap());
+    }
+    assert_eq!(s.current_facts(), &solve(x)[..]);
+}
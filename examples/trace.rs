@@ -0,0 +1,21 @@
+extern crate reachability_solver;
+
+use reachability_solver::{solve, trace};
+
+fn main() {
+    let x = vec![[1, 2], [2, 3]];
+    let t = trace::solve(x.clone());
+    println!("{}\n", t);
+    println!("{}\n", t.to_json());
+    assert_eq!(t.solution, solve(x));
+
+    // Two initial nodes, where the path from `2` joins the path from `1` at `4`.
+    //
+    // 1 → 4 → 5
+    //     ↑
+    // 2 → 3
+    let x = vec![[1, 4], [2, 3], [3, 4], [4, 5]];
+    let t = trace::solve(x.clone());
+    println!("{}", t);
+    assert_eq!(t.solution, solve(x));
+}
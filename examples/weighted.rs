@@ -0,0 +1,21 @@
+extern crate reachability_solver;
+
+use reachability_solver::weighted;
+
+fn main() {
+    // Harry starts at `1` and Cedric starts at `2`.
+    // Both try to reach the Triwizard Cup at `5`.
+    //
+    //         1
+    //         ↓ 3
+    // 2 → 3 → 4 → 5
+    //   1   1   2
+    //
+    let edges = vec![[1, 4], [2, 3], [3, 4], [4, 5]];
+    let weights = vec![3, 1, 1, 2];
+    let (pairs, dist) = weighted::solve(&edges, &weights);
+    println!("{:?}", pairs);
+    for d in &dist {
+        println!("{:?} distance {} hops {}", d.pair, d.distance, d.hops);
+    }
+}
@@ -0,0 +1,54 @@
+//! ### Agents - Simultaneous reachability for multiple agents
+//!
+//! In the Triwizard Tournament, Harry starts at `1`, Cedric starts at `2`,
+//! and both try to reach the Triwizard Cup at `5`.
+//! Each agent is a pair `[start, goal]`, and all agents are answered from a single solve.
+//!
+//! ```text
+//! [1, 4], [2, 3], [3, 4], [4, 5]
+//! agents: [1, 5], [2, 5]
+//! ----------------------------------------
+//! Reaches, Reaches
+//! ```
+//!
+//! The solution only contains pairs of initial and terminal nodes.
+//! When a start node is not initial or a goal node is not terminal,
+//! the solution can not tell whether the agent reaches its goal.
+//! This is reported instead of answering `false`.
+//! Use `reaches` to answer such agents directly.
+
+/// The answer for a single agent.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Answer {
+    /// The agent reaches its goal.
+    Reaches,
+    /// The start is an initial node and the goal is a terminal node,
+    /// but the goal is not reachable from the start.
+    Blocked,
+    /// The node is not part of the maze.
+    NotInMaze(usize),
+    /// The start node has incoming edges, so it is not in the solution.
+    StartNotInitial(usize),
+    /// The goal node has outgoing edges, so it is not in the solution.
+    GoalNotTerminal(usize),
+}
+
+/// Answers whether each agent reaches its goal, using a single solve.
+pub fn solve(edges: &[[usize; 2]], agents: &[[usize; 2]]) -> Vec<Answer> {
+    use crate::solution::Solution;
+
+    let solution = Solution::new(edges.into());
+    agents.iter().map(|&[start, goal]| {
+        for &a in &[start, goal] {
+            if !edges.iter().any(|e| e.contains(&a)) {return Answer::NotInMaze(a)}
+        }
+        if edges.iter().any(|e| e[1] == start) {return Answer::StartNotInitial(start)}
+        if edges.iter().any(|e| e[0] == goal) {return Answer::GoalNotTerminal(goal)}
+        if solution.contains(start, goal) {Answer::Reaches} else {Answer::Blocked}
+    }).collect()
+}
+
+/// Returns `true` if every agent reaches its goal.
+pub fn all_reach(answers: &[Answer]) -> bool {
+    answers.iter().all(|&a| a == Answer::Reaches)
+}
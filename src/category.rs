@@ -0,0 +1,129 @@
+//! ### Category - Mazes as preorder categories
+//!
+//! Every maze generates a category where:
+//!
+//! - The objects are the nodes
+//! - There is a morphism `[a, b]` when `b` is reachable from `a`
+//! - The identity morphism of `a` is `[a, a]`
+//! - Composing `[a, b]` with `[b, c]` gives `[a, c]`
+//!
+//! Since there is at most one morphism between two objects, this is a preorder category.
+//!
+//! The crate docs use a simplified definition of initial and terminal objects,
+//! where an initial object has no incoming edges and a terminal object has no outgoing edges.
+//! In category theory, an initial object has exactly one morphism to every object,
+//! and a terminal object has exactly one morphism from every object.
+//! The two definitions disagree, e.g. when the maze has several initial nodes,
+//! or when every node lies on a cycle:
+//!
+//! ```text
+//! [1, 3], [2, 3]      1 and 2 are sources, but neither reaches the other
+//! [1, 2], [2, 1]      1 and 2 reach everything, but both have incoming edges
+//! ```
+
+use crate::closure::Matrix;
+
+/// The preorder category generated by a maze.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Category {
+    matrix: Matrix,
+    sources: Vec<usize>,
+    sinks: Vec<usize>,
+}
+
+/// Describes a disagreement between the simplified and the categorical definitions.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Disagreement {
+    /// The node has no incoming edges, but is not an initial object.
+    SourceNotInitial(usize),
+    /// The node is an initial object, but has incoming edges.
+    InitialNotSource(usize),
+    /// The node has no outgoing edges, but is not a terminal object.
+    SinkNotTerminal(usize),
+    /// The node is a terminal object, but has outgoing edges.
+    TerminalNotSink(usize),
+}
+
+impl Category {
+    /// Creates the preorder category of a maze.
+    ///
+    /// Self-loops are treated as identity morphisms.
+    pub fn new(x: &[[usize; 2]]) -> Category {
+        let matrix = Matrix::reflexive(x);
+        let sources = matrix.nodes().iter().cloned()
+            .filter(|&a| !x.iter().any(|&[b, c]| b != c && c == a)).collect();
+        let sinks = matrix.nodes().iter().cloned()
+            .filter(|&a| !x.iter().any(|&[b, c]| b != c && b == a)).collect();
+        Category {matrix, sources, sinks}
+    }
+
+    /// Returns the objects in increasing order.
+    pub fn objects(&self) -> &[usize] {self.matrix.nodes()}
+
+    /// Returns the identity morphism of an object.
+    ///
+    /// Returns `None` if `a` is not an object.
+    pub fn identity(&self, a: usize) -> Option<[usize; 2]> {
+        if self.matrix.reaches(a, a) {Some([a, a])} else {None}
+    }
+
+    /// Returns the morphisms from `a` to `b`.
+    ///
+    /// In a preorder category, there is at most one.
+    pub fn hom(&self, a: usize, b: usize) -> Vec<[usize; 2]> {
+        if self.matrix.reaches(a, b) {vec![[a, b]]} else {vec![]}
+    }
+
+    /// Composes `f: a -> b` with `g: b -> c` into `a -> c`.
+    ///
+    /// Returns `None` if `f` or `g` is not a morphism, or if they are not composable.
+    pub fn compose(&self, f: [usize; 2], g: [usize; 2]) -> Option<[usize; 2]> {
+        if f[1] != g[0] || !self.matrix.reaches(f[0], f[1]) || !self.matrix.reaches(g[0], g[1]) {
+            return None;
+        }
+        Some([f[0], g[1]])
+    }
+
+    /// Returns the initial objects, which have a morphism to every object.
+    pub fn initial_objects(&self) -> Vec<usize> {
+        let objects = self.objects();
+        objects.iter().cloned()
+            .filter(|&a| objects.iter().all(|&b| self.matrix.reaches(a, b))).collect()
+    }
+
+    /// Returns the terminal objects, which have a morphism from every object.
+    pub fn terminal_objects(&self) -> Vec<usize> {
+        let objects = self.objects();
+        objects.iter().cloned()
+            .filter(|&a| objects.iter().all(|&b| self.matrix.reaches(b, a))).collect()
+    }
+
+    /// Returns the nodes without incoming edges, in increasing order.
+    pub fn sources(&self) -> &[usize] {&self.sources}
+
+    /// Returns the nodes without outgoing edges, in increasing order.
+    pub fn sinks(&self) -> &[usize] {&self.sinks}
+
+    /// Lists where the simplified definition of initial and terminal objects
+    /// disagrees with the categorical one.
+    pub fn disagreements(&self) -> Vec<Disagreement> {
+        use self::Disagreement::*;
+
+        let initial = self.initial_objects();
+        let terminal = self.terminal_objects();
+        let mut r = vec![];
+        for &a in &self.sources {
+            if !initial.contains(&a) {r.push(SourceNotInitial(a))}
+        }
+        for &a in &initial {
+            if !self.sources.contains(&a) {r.push(InitialNotSource(a))}
+        }
+        for &a in &self.sinks {
+            if !terminal.contains(&a) {r.push(SinkNotTerminal(a))}
+        }
+        for &a in &terminal {
+            if !self.sinks.contains(&a) {r.push(TerminalNotSink(a))}
+        }
+        r
+    }
+}
@@ -0,0 +1,159 @@
+//! ### Closure - Transitive closure and reachability matrix
+//!
+//! The solver only keeps pairs of initial and terminal nodes.
+//! The transitive closure keeps every pair `[a, b]` where `b` is reachable from `a`,
+//! including intermediate nodes.
+//!
+//! For example:
+//!
+//! ```text
+//! [2, 3], [3, 4]
+//! ----------------------
+//! [2, 3], [2, 4], [3, 4]
+//! ```
+//!
+//! The reflexive transitive closure also adds `[a, a]` for every node,
+//! which corresponds to the identity morphisms of a category.
+
+use std::collections::HashMap;
+
+/// Stores reachability between every pair of nodes as a bitset.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Matrix {
+    nodes: Vec<usize>,
+    index: HashMap<usize, usize>,
+    words: usize,
+    bits: Vec<u64>,
+}
+
+impl Matrix {
+    /// Computes the transitive closure of a maze.
+    ///
+    /// A node reaches itself only when it lies on a cycle.
+    pub fn new(x: &[[usize; 2]]) -> Matrix {
+        use crate::graph::{nodes, strongly_connected};
+
+        let nodes = nodes(x);
+        let index: HashMap<usize, usize> = nodes.iter().enumerate().map(|(i, &a)| (a, i)).collect();
+        let words = nodes.len().div_ceil(64);
+        let mut m = Matrix {bits: vec![0; words * nodes.len()], nodes, index, words};
+
+        // Visit components in reverse topological order,
+        // such that every successor is complete before it is merged.
+        let (components, component_of) = strongly_connected(x);
+        let mut out: Vec<Vec<usize>> = vec![vec![]; components.len()];
+        let mut cyclic: Vec<bool> = components.iter().map(|c| c.len() > 1).collect();
+        for &[a, b] in x {
+            let (i, j) = (component_of[&a], component_of[&b]);
+            if i == j {cyclic[i] = true} else {out[i].push(m.index[&b])}
+        }
+        for (i, c) in components.iter().enumerate().rev() {
+            let row = m.index[&c[0]];
+            for &b in &out[i] {
+                m.set(row, b);
+                m.merge(row, b);
+            }
+            if cyclic[i] {
+                for &a in c {m.set(row, m.index[&a])}
+            }
+            for &a in &c[1..] {
+                let other = m.index[&a];
+                m.merge(other, row);
+            }
+        }
+        m
+    }
+
+    /// Computes the reflexive transitive closure of a maze.
+    ///
+    /// Every node reaches itself.
+    pub fn reflexive(x: &[[usize; 2]]) -> Matrix {
+        let mut m = Matrix::new(x);
+        for i in 0..m.nodes.len() {m.set(i, i)}
+        m
+    }
+
+    /// Returns the nodes of the maze in increasing order.
+    pub fn nodes(&self) -> &[usize] {&self.nodes}
+
+    /// Returns `true` if `b` is reachable from `a`.
+    ///
+    /// Returns `false` if either node is not in the maze.
+    pub fn reaches(&self, a: usize, b: usize) -> bool {
+        match (self.index.get(&a), self.index.get(&b)) {
+            (Some(&i), Some(&j)) => self.bits[i * self.words + j / 64] >> (j % 64) & 1 == 1,
+            _ => false,
+        }
+    }
+
+    /// Returns the nodes reachable from `a`, in increasing order.
+    pub fn targets_of(&self, a: usize) -> Vec<usize> {
+        self.nodes.iter().cloned().filter(|&b| self.reaches(a, b)).collect()
+    }
+
+    /// Returns the closure as a list of edges, sorted.
+    pub fn edges(&self) -> Vec<[usize; 2]> {
+        let mut r = vec![];
+        for &a in &self.nodes {
+            for &b in &self.nodes {
+                if self.reaches(a, b) {r.push([a, b])}
+            }
+        }
+        r
+    }
+
+    fn set(&mut self, i: usize, j: usize) {
+        self.bits[i * self.words + j / 64] |= 1 << (j % 64);
+    }
+
+    // Adds the row `j` to the row `i`.
+    fn merge(&mut self, i: usize, j: usize) {
+        for k in 0..self.words {
+            self.bits[i * self.words + k] |= self.bits[j * self.words + k];
+        }
+    }
+}
+
+/// Returns the transitive closure of a maze, sorted.
+pub fn closure(x: &[[usize; 2]]) -> Vec<[usize; 2]> {
+    Matrix::new(x).edges()
+}
+
+/// Returns the reflexive transitive closure of a maze, sorted.
+pub fn reflexive_closure(x: &[[usize; 2]]) -> Vec<[usize; 2]> {
+    Matrix::reflexive(x).edges()
+}
+
+/// Returns the transitive reduction of a maze, sorted.
+///
+/// This is the smallest maze with the same transitive closure.
+/// For a maze without cycles it is unique and is called the Hasse diagram.
+///
+/// Every strongly connected component is replaced by a single cycle through its nodes,
+/// and edges between components start and end at the smallest node of each component.
+pub fn transitive_reduction(x: &[[usize; 2]]) -> Vec<[usize; 2]> {
+    use crate::scc::condense;
+
+    let c = condense(x);
+    let m = Matrix::new(&c.edges);
+    let mut r = vec![];
+    for comp in &c.components {
+        if comp.len() > 1 {
+            for i in 0..comp.len() {
+                r.push([comp[i], comp[(i + 1) % comp.len()]]);
+            }
+        }
+    }
+    for &[a, b] in x {
+        if a == b && c.components[c.component_of[&a]].len() == 1 {r.push([a, a])}
+    }
+    for &[i, j] in &c.edges {
+        let redundant = c.edges.iter().any(|&[k, l]| k == i && l != j && m.reaches(l, j));
+        if !redundant {
+            r.push([c.components[i][0], c.components[j][0]]);
+        }
+    }
+    r.sort();
+    r.dedup();
+    r
+}
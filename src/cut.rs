@@ -0,0 +1,162 @@
+//! ### Cut - Minimum cuts between initial and terminal nodes
+//!
+//! A cut is a set of edges, or of nodes, whose removal disconnects
+//! initial nodes from terminal nodes.
+//! The smallest cut tells how many corridors must be blocked to make the maze unsolvable.
+//!
+//! By the max-flow min-cut theorem, the size of the smallest cut equals the maximum flow,
+//! which is computed using the Edmonds-Karp algorithm.
+//!
+//! For example:
+//!
+//! ```text
+//! [1, 2], [1, 3], [2, 4], [3, 4], [4, 5]
+//! ----------------------------------------
+//! edge cut [1, 5]: [4, 5]
+//! node cut [1, 5]: 4
+//! ```
+//!
+//! Every cut is verified by solving the maze without the cut,
+//! checking that the pairs are no longer in the solution.
+
+use std::collections::HashMap;
+
+use crate::flow::{Network, INF};
+
+/// A set of edges whose removal disconnects pairs in the solution.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct EdgeCut {
+    /// The edges of the cut, sorted.
+    pub edges: Vec<[usize; 2]>,
+    /// Whether solving the maze without the cut drops the pairs.
+    pub verified: bool,
+}
+
+/// A set of nodes whose removal disconnects pairs in the solution.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct NodeCut {
+    /// The nodes of the cut, sorted.
+    pub nodes: Vec<usize>,
+    /// Whether solving the maze without the cut drops the pairs.
+    pub verified: bool,
+}
+
+/// Finds a minimum set of edges that disconnects `b` from `a`.
+///
+/// The node `a` should be initial and `b` should be terminal.
+/// Duplicate edges are treated as a single edge.
+pub fn edge_cut(x: &[[usize; 2]], a: usize, b: usize) -> EdgeCut {
+    edge_cut_between(x, &[a], &[b], &[[a, b]])
+}
+
+/// Finds a minimum set of edges that disconnects every pair in the solution.
+pub fn edge_cut_all(x: &[[usize; 2]]) -> EdgeCut {
+    let pairs = crate::solve(x.into());
+    let (initials, terminals) = ends(&pairs);
+    edge_cut_between(x, &initials, &terminals, &pairs)
+}
+
+/// Finds a minimum set of nodes that disconnects `b` from `a`.
+///
+/// The node `a` should be initial and `b` should be terminal.
+/// Returns `None` if there is an edge from `a` to `b`, since no node can be removed then.
+pub fn node_cut(x: &[[usize; 2]], a: usize, b: usize) -> Option<NodeCut> {
+    node_cut_between(x, &[a], &[b], &[[a, b]])
+}
+
+/// Finds a minimum set of nodes that disconnects every pair in the solution.
+///
+/// Initial and terminal nodes are not removed.
+/// Returns `None` if there is an edge from an initial node to a terminal node.
+pub fn node_cut_all(x: &[[usize; 2]]) -> Option<NodeCut> {
+    let pairs = crate::solve(x.into());
+    let (initials, terminals) = ends(&pairs);
+    node_cut_between(x, &initials, &terminals, &pairs)
+}
+
+fn ends(pairs: &[[usize; 2]]) -> (Vec<usize>, Vec<usize>) {
+    let mut initials: Vec<usize> = pairs.iter().map(|e| e[0]).collect();
+    initials.sort();
+    initials.dedup();
+    let mut terminals: Vec<usize> = pairs.iter().map(|e| e[1]).collect();
+    terminals.sort();
+    terminals.dedup();
+    (initials, terminals)
+}
+
+// Returns `true` if none of the pairs are in the solution.
+fn verify(rest: Vec<[usize; 2]>, pairs: &[[usize; 2]]) -> bool {
+    let solution = crate::solve(rest);
+    pairs.iter().all(|p| !solution.contains(p))
+}
+
+fn edge_cut_between(
+    x: &[[usize; 2]],
+    sources: &[usize],
+    sinks: &[usize],
+    pairs: &[[usize; 2]]
+) -> EdgeCut {
+    use crate::graph::nodes;
+
+    let nodes = nodes(x);
+    let index: HashMap<usize, usize> = nodes.iter().enumerate().map(|(i, &a)| (a, i)).collect();
+    let (s, t) = (nodes.len(), nodes.len() + 1);
+    let mut net = Network::new(nodes.len() + 2);
+    let mut edges: Vec<[usize; 2]> = x.iter().cloned().filter(|e| e[0] != e[1]).collect();
+    edges.sort();
+    edges.dedup();
+    for &[a, b] in &edges {
+        net.add(index[&a], index[&b], 1);
+    }
+    for a in sources {
+        if let Some(&i) = index.get(a) {net.add(s, i, INF);}
+    }
+    for b in sinks {
+        if let Some(&i) = index.get(b) {net.add(i, t, INF);}
+    }
+    net.max_flow(s, t);
+
+    let side = net.source_side(s);
+    edges.retain(|&[a, b]| side[index[&a]] && !side[index[&b]]);
+    let rest = x.iter().cloned().filter(|e| !edges.contains(e)).collect();
+    EdgeCut {verified: verify(rest, pairs), edges}
+}
+
+fn node_cut_between(
+    x: &[[usize; 2]],
+    sources: &[usize],
+    sinks: &[usize],
+    pairs: &[[usize; 2]]
+) -> Option<NodeCut> {
+    use crate::graph::nodes;
+
+    if x.iter().any(|e| sources.contains(&e[0]) && sinks.contains(&e[1])) {return None}
+
+    // Every node is split into an incoming and an outgoing part,
+    // connected by an arc that represents the node itself.
+    let nodes = nodes(x);
+    let index: HashMap<usize, usize> = nodes.iter().enumerate().map(|(i, &a)| (a, i)).collect();
+    let (s, t) = (2 * nodes.len(), 2 * nodes.len() + 1);
+    let mut net = Network::new(2 * nodes.len() + 2);
+    for (i, a) in nodes.iter().enumerate() {
+        let fixed = sources.contains(a) || sinks.contains(a);
+        net.add(2 * i, 2 * i + 1, if fixed {INF} else {1});
+    }
+    for &[a, b] in x {
+        if a != b {net.add(2 * index[&a] + 1, 2 * index[&b], INF);}
+    }
+    for a in sources {
+        if let Some(&i) = index.get(a) {net.add(s, 2 * i, INF);}
+    }
+    for b in sinks {
+        if let Some(&i) = index.get(b) {net.add(2 * i + 1, t, INF);}
+    }
+    net.max_flow(s, t);
+
+    let side = net.source_side(s);
+    let cut: Vec<usize> = nodes.iter().enumerate()
+        .filter(|&(i, _)| side[2 * i] && !side[2 * i + 1])
+        .map(|(_, &a)| a).collect();
+    let rest = x.iter().cloned().filter(|e| !cut.contains(&e[0]) && !cut.contains(&e[1])).collect();
+    Some(NodeCut {verified: verify(rest, pairs), nodes: cut})
+}
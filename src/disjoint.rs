@@ -0,0 +1,116 @@
+//! ### Disjoint - Paths that do not share corridors
+//!
+//! Two agents can reach a goal without meeting when there are paths that share no edges,
+//! or, stronger, paths that share no nodes except the start and the goal.
+//!
+//! The maximum number of disjoint paths equals the size of the minimum cut,
+//! and the paths are found by decomposing a maximum flow.
+//!
+//! For example:
+//!
+//! ```text
+//! [1, 2], [1, 3], [2, 4], [3, 4], [4, 5], [4, 6], [5, 7], [6, 7]
+//! ----------------------------------------
+//! edge disjoint [1, 7]: [1, 2, 4, 5, 7], [1, 3, 4, 6, 7]
+//! node disjoint [1, 7]: [1, 2, 4, 5, 7]
+//! ```
+
+use std::collections::HashMap;
+
+use crate::flow::{Network, INF};
+
+/// Finds a maximum set of paths from `a` to `b` that share no edges.
+///
+/// Every path is a list of nodes, starting with `a` and ending with `b`.
+/// Duplicate edges are treated as a single edge.
+pub fn edge_disjoint(x: &[[usize; 2]], a: usize, b: usize) -> Vec<Vec<usize>> {
+    use crate::graph::nodes;
+
+    let nodes = nodes(x);
+    let index: HashMap<usize, usize> = nodes.iter().enumerate().map(|(i, &a)| (a, i)).collect();
+    let (s, t) = match (index.get(&a), index.get(&b)) {
+        (Some(&s), Some(&t)) if s != t => (s, t),
+        _ => return vec![],
+    };
+    let mut net = Network::new(nodes.len());
+    let mut arcs = vec![];
+    let mut edges: Vec<[usize; 2]> = x.iter().cloned().filter(|e| e[0] != e[1]).collect();
+    edges.sort();
+    edges.dedup();
+    for &[c, d] in &edges {
+        let (i, j) = (index[&c], index[&d]);
+        arcs.push((net.add(i, j, 1), i, j));
+    }
+    net.max_flow(s, t);
+    decompose(&net, &arcs, s, t).into_iter()
+        .map(|p| p.into_iter().map(|i| nodes[i]).collect()).collect()
+}
+
+/// Finds a maximum set of paths from `a` to `b` that share no nodes except `a` and `b`.
+///
+/// Every path is a list of nodes, starting with `a` and ending with `b`.
+pub fn node_disjoint(x: &[[usize; 2]], a: usize, b: usize) -> Vec<Vec<usize>> {
+    use crate::graph::nodes;
+
+    let nodes = nodes(x);
+    let index: HashMap<usize, usize> = nodes.iter().enumerate().map(|(i, &a)| (a, i)).collect();
+    let (s, t) = match (index.get(&a), index.get(&b)) {
+        (Some(&s), Some(&t)) if s != t => (2 * s, 2 * t + 1),
+        _ => return vec![],
+    };
+    // Every node is split into an incoming and an outgoing part,
+    // connected by an arc that can be used by a single path.
+    let mut net = Network::new(2 * nodes.len());
+    let mut arcs = vec![];
+    for (i, c) in nodes.iter().enumerate() {
+        let cap = if *c == a || *c == b {INF} else {1};
+        arcs.push((net.add(2 * i, 2 * i + 1, cap), 2 * i, 2 * i + 1));
+    }
+    let mut edges: Vec<[usize; 2]> = x.iter().cloned().filter(|e| e[0] != e[1]).collect();
+    edges.sort();
+    edges.dedup();
+    for &[c, d] in &edges {
+        let (i, j) = (2 * index[&c] + 1, 2 * index[&d]);
+        arcs.push((net.add(i, j, 1), i, j));
+    }
+    net.max_flow(s, t);
+    decompose(&net, &arcs, s, t).into_iter()
+        .map(|p| p.into_iter().step_by(2).map(|i| nodes[i / 2]).collect()).collect()
+}
+
+/// Finds a maximum set of edge disjoint paths for every pair in the solution.
+pub fn edge_disjoint_all(x: &[[usize; 2]]) -> Vec<([usize; 2], Vec<Vec<usize>>)> {
+    crate::solve(x.into()).into_iter().map(|[a, b]| ([a, b], edge_disjoint(x, a, b))).collect()
+}
+
+/// Finds a maximum set of node disjoint paths for every pair in the solution.
+pub fn node_disjoint_all(x: &[[usize; 2]]) -> Vec<([usize; 2], Vec<Vec<usize>>)> {
+    crate::solve(x.into()).into_iter().map(|[a, b]| ([a, b], node_disjoint(x, a, b))).collect()
+}
+
+/// Decomposes a flow into paths from `s` to `t`.
+///
+/// Flow around cycles is dropped.
+fn decompose(net: &Network, arcs: &[(usize, usize, usize)], s: usize, t: usize) -> Vec<Vec<usize>> {
+    let mut out: HashMap<usize, Vec<usize>> = HashMap::new();
+    for &(i, a, b) in arcs {
+        for _ in 0..net.flow(i) {
+            out.entry(a).or_default().push(b);
+        }
+    }
+    let mut r = vec![];
+    while out.get(&s).map(|o| !o.is_empty()).unwrap_or(false) {
+        let mut path = vec![s];
+        let mut a = s;
+        while a != t {
+            a = out.get_mut(&a).unwrap().pop().unwrap();
+            if let Some(k) = path.iter().position(|&b| b == a) {
+                path.truncate(k);
+            }
+            path.push(a);
+        }
+        r.push(path);
+    }
+    r.sort();
+    r
+}
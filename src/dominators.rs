@@ -0,0 +1,151 @@
+//! ### Dominators - Mandatory waypoints between initial and terminal nodes
+//!
+//! A node `d` dominates a node `b` from a root `a`, when every path from `a` to `b` passes through `d`.
+//! The closest such node is called the immediate dominator,
+//! and the immediate dominators form a tree called the dominator tree.
+//!
+//! Post-dominators are the same concept for the opposite maze:
+//! every path from `b` to an exit passes through a post-dominator of `b`.
+//!
+//! For example, when a single gate `4` leads to the Triwizard Cup:
+//!
+//! ```text
+//! [1, 3], [2, 3], [3, 4], [4, 5]
+//! ----------------------------------------
+//! [1, 5] waypoints 3, 4 edges [1, 3], [3, 4], [4, 5]
+//! [2, 5] waypoints 3, 4 edges [2, 3], [3, 4], [4, 5]
+//! ```
+
+use std::collections::HashMap;
+
+/// Stores the mandatory waypoints of a pair in the solution.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Waypoints {
+    /// The pair of initial and terminal node.
+    pub pair: [usize; 2],
+    /// The nodes that every path passes through, in the order they are visited.
+    ///
+    /// The initial and terminal nodes are not included.
+    pub nodes: Vec<usize>,
+    /// The edges whose removal breaks the pair, sorted.
+    pub edges: Vec<[usize; 2]>,
+}
+
+/// Computes the dominator tree of the nodes reachable from `root`.
+///
+/// Returns a map from every reachable node to its immediate dominator.
+/// The root is not included.
+///
+/// Uses the iterative algorithm by Cooper, Harvey and Kennedy.
+pub fn dominator_tree(x: &[[usize; 2]], root: usize) -> HashMap<usize, usize> {
+    use crate::graph::successors;
+
+    let succ = successors(x);
+
+    // Depth first search for reverse postorder.
+    let mut order = vec![];
+    let mut visited = std::collections::HashSet::new();
+    visited.insert(root);
+    let mut stack = vec![(root, 0)];
+    while let Some(&(a, i)) = stack.last() {
+        let s = succ.get(&a).map(|s| &s[..]).unwrap_or(&[]);
+        if i < s.len() {
+            stack.last_mut().unwrap().1 += 1;
+            if visited.insert(s[i]) {stack.push((s[i], 0))}
+        } else {
+            order.push(a);
+            stack.pop();
+        }
+    }
+    order.reverse();
+    let number: HashMap<usize, usize> = order.iter().enumerate().map(|(i, &a)| (a, i)).collect();
+
+    let mut pred: Vec<Vec<usize>> = vec![vec![]; order.len()];
+    for (&a, s) in &succ {
+        if let Some(&i) = number.get(&a) {
+            for b in s {pred[number[b]].push(i)}
+        }
+    }
+
+    let mut idom: Vec<Option<usize>> = vec![None; order.len()];
+    idom[0] = Some(0);
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for b in 1..order.len() {
+            let mut new_idom: Option<usize> = None;
+            for &p in &pred[b] {
+                if idom[p].is_none() {continue}
+                new_idom = Some(match new_idom {
+                    None => p,
+                    Some(mut f) => {
+                        let mut g = p;
+                        while f != g {
+                            while f > g {f = idom[f].unwrap()}
+                            while g > f {g = idom[g].unwrap()}
+                        }
+                        f
+                    }
+                });
+            }
+            if new_idom != idom[b] {
+                idom[b] = new_idom;
+                changed = true;
+            }
+        }
+    }
+
+    (1..order.len()).map(|b| (order[b], order[idom[b].unwrap()])).collect()
+}
+
+/// Computes the post-dominator tree of the nodes that reach `exit`.
+///
+/// Returns a map from every node that reaches `exit` to its immediate post-dominator.
+/// The exit is not included.
+pub fn post_dominator_tree(x: &[[usize; 2]], exit: usize) -> HashMap<usize, usize> {
+    use crate::ops::opposite;
+
+    dominator_tree(&opposite(x), exit)
+}
+
+/// Computes the mandatory waypoints and edges for every pair in the solution.
+///
+/// Duplicate edges are treated as a single edge.
+pub fn waypoints(x: &[[usize; 2]]) -> Vec<Waypoints> {
+    use crate::{reachable_from, reaches, solve};
+    use crate::ops::opposite;
+
+    let mut all: Vec<[usize; 2]> = x.to_vec();
+    all.sort();
+    all.dedup();
+    let back = opposite(&all);
+
+    let mut trees: HashMap<usize, HashMap<usize, usize>> = HashMap::new();
+    let mut rest = Vec::with_capacity(all.len());
+    let mut r = vec![];
+    for [a, b] in solve(x.into()) {
+        let tree = trees.entry(a).or_insert_with(|| dominator_tree(x, a));
+        let mut nodes = vec![];
+        let mut c = tree[&b];
+        while c != a {
+            nodes.push(c);
+            c = tree[&c];
+        }
+        nodes.reverse();
+
+        // Only edges on a path from `a` to `b` can be mandatory.
+        let from_a = reachable_from(&all, a);
+        let to_b = reachable_from(&back, b);
+        let mut edges = vec![];
+        for &e in &all {
+            let on_path = (e[0] == a || from_a.binary_search(&e[0]).is_ok()) &&
+                          (e[1] == b || to_b.binary_search(&e[1]).is_ok());
+            if !on_path {continue}
+            rest.clear();
+            rest.extend(all.iter().cloned().filter(|f| *f != e));
+            if !reaches(&rest, a, b) {edges.push(e)}
+        }
+        r.push(Waypoints {pair: [a, b], nodes, edges});
+    }
+    r
+}
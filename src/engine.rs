@@ -0,0 +1,143 @@
+//! A stepwise version of `linear_solver::solve_minimum`.
+//!
+//! The solver in `linear_solver` runs to completion in a single call,
+//! using a function pointer for inference.
+//! This engine performs one inference at a time,
+//! such that the caller can observe, limit or interrupt the solver.
+
+use std::collections::HashSet;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use linear_solver::Inference;
+
+/// Solves by applying one inference at a time.
+pub(crate) struct Engine {
+    facts: Vec<[usize; 2]>,
+    cache: HashSet<[usize; 2]>,
+    // Hashes of previous sets of facts.
+    // Used to detect whether a given set of facts has already been inferred.
+    seen: HashSet<u64>,
+    // The least set of facts found while searching for the minimum in a cycle.
+    minimum: Option<Vec<[usize; 2]>>,
+    done: bool,
+}
+
+impl Engine {
+    /// Creates a new engine with starting facts.
+    pub fn new(facts: Vec<[usize; 2]>) -> Engine {
+        let cache = facts.iter().cloned().collect();
+        Engine {facts, cache, seen: HashSet::new(), minimum: None, done: false}
+    }
+
+    /// Returns the current facts.
+    pub fn facts(&self) -> &[[usize; 2]] {&self.facts}
+
+    /// Performs one step of the solver, using `infer` for inference.
+    ///
+    /// Returns `false` when the solver is finished.
+    pub fn step<F>(&mut self, infer: F) -> bool
+        where F: FnOnce(&HashSet<[usize; 2]>, &[[usize; 2]]) -> Option<Inference<[usize; 2]>>
+    {
+        if self.done {return false}
+
+        let h = {
+            let mut hasher = DefaultHasher::new();
+            self.facts.hash(&mut hasher);
+            hasher.finish()
+        };
+        match self.minimum {
+            None if self.seen.contains(&h) => {
+                self.minimum = Some(self.facts.clone());
+                self.seen.clear();
+            }
+            Some(ref fa) if self.seen.contains(&h) => {
+                // Completed cycle, minimum set of facts is found.
+                if fa.len() < self.facts.len() {
+                    self.facts = fa.clone();
+                }
+                self.done = true;
+                return false;
+            }
+            Some(ref fa) if self.facts.len() < fa.len() => {
+                // Found less amounts of facts in cycle.
+                self.minimum = Some(self.facts.clone());
+            }
+            _ => {}
+        }
+        self.seen.insert(h);
+
+        match infer(&self.cache, &self.facts) {
+            None => {
+                self.done = true;
+                return false;
+            }
+            Some(Inference::ManyTrue {from}) => self.remove_from(&from),
+            Some(Inference::OneTrue {from}) => self.remove_from(&[from]),
+            Some(Inference::Simplify {from, to}) => {
+                self.remove_from(&from);
+                self.facts.push(to);
+                self.cache.insert(to);
+            }
+            Some(Inference::SimplifyOne {from, to}) => {
+                self.replace(&from, &to);
+                self.cache.insert(to);
+            }
+            Some(Inference::SimplifyMany {from, to}) => {
+                self.remove_from(&from);
+                for &fact in &to {
+                    self.cache.insert(fact);
+                }
+                self.facts.extend(to);
+            }
+            Some(Inference::Propagate(x)) => {
+                self.facts.push(x);
+                self.cache.insert(x);
+            }
+        }
+        true
+    }
+
+    fn remove_from(&mut self, from: &[[usize; 2]]) {
+        for new_fact in from {
+            let mut unique = false;
+            let mut i = 0;
+            loop {
+                if i >= self.facts.len() {break};
+                if new_fact == &self.facts[i] {
+                    if unique {
+                        unique = false;
+                        break;
+                    }
+                    // Since using swap remove,
+                    // should check the same index twice.
+                    self.facts.swap_remove(i);
+                    unique = true;
+                } else {
+                    i += 1;
+                }
+            }
+            if unique {
+                self.cache.remove(new_fact);
+            }
+        }
+    }
+
+    // Replace existing fact with new one to stabilize order.
+    fn replace(&mut self, from: &[usize; 2], to: &[usize; 2]) {
+        let mut unique = false;
+        for fact in &mut self.facts {
+            if from == fact {
+                if unique {
+                    unique = false;
+                    break;
+                }
+                *fact = *to;
+                unique = true;
+            }
+        }
+        if unique {
+            self.cache.remove(from);
+        }
+    }
+}
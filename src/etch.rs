@@ -15,9 +15,9 @@
 /// The first argument should be the solved maze.
 /// The second argument should be the original maze.
 pub fn initial(a: &[[usize; 2]], b: &mut Vec<[usize; 2]>) {
-    for i in 0..a.len() {
+    for ea in a {
         for j in (0..b.len()).rev() {
-            if a[i][0] == b[j][0] {
+            if ea[0] == b[j][0] {
                 b.swap_remove(j);
             }
         }
@@ -26,9 +26,9 @@ pub fn initial(a: &[[usize; 2]], b: &mut Vec<[usize; 2]>) {
 
 /// Etches away terminal nodes.
 pub fn terminal(a: &[[usize; 2]], b: &mut Vec<[usize; 2]>) {
-    for i in 0..a.len() {
+    for ea in a {
         for j in (0..b.len()).rev() {
-            if a[i][1] == b[j][1] {
+            if ea[1] == b[j][1] {
                 b.swap_remove(j);
             }
         }
@@ -44,16 +44,92 @@ pub fn terminal(a: &[[usize; 2]], b: &mut Vec<[usize; 2]>) {
 ///
 /// The cardinality measures the maximum number of steps required to
 /// reach any goal, plus one.
+///
+/// This never returns when the maze contains a cycle, see `try_cardinality`.
 pub fn cardinality(x: &[[usize; 2]]) -> usize {
     use crate::solve;
 
-    if x.len() == 0 {return 0}
+    if x.is_empty() {return 0}
     let mut b: Vec<[usize; 2]> = x.into();
     let mut n = 1;
-    while b.len() > 0 {
+    while !b.is_empty() {
         let a = solve(b.clone());
         initial(&a, &mut b);
         n += 1;
     }
     n
 }
+
+/// Measures the cardinality of a maze, validating the input.
+///
+/// Returns an error if the maze contains self-loops or duplicate edges,
+/// or if etching gets stuck because no initial node reaches a terminal node,
+/// which happens when the maze contains a cycle.
+pub fn try_cardinality(x: &[[usize; 2]]) -> Result<usize, crate::Error> {
+    use crate::{solve, validate, Error};
+
+    validate(x)?;
+    let mut b: Vec<[usize; 2]> = x.into();
+    let mut n = if x.is_empty() {0} else {1};
+    while !b.is_empty() {
+        let a = solve(b.clone());
+        let len = b.len();
+        initial(&a, &mut b);
+        if b.len() == len {
+            b.sort();
+            return Err(Error::CycleOnly(b));
+        }
+        n += 1;
+    }
+    Ok(n)
+}
+
+/// Computes the etch signature of a maze.
+///
+/// The signature is the sequence of solved mazes obtained by
+/// solving the maze and etching away initial nodes repeatedly,
+/// until there are no edges left.
+/// Every solved maze in the sequence is canonical, as returned by `solve_sorted`.
+///
+/// Two mazes with the same signature can not be told apart by initial etching,
+/// even if the original mazes are different.
+///
+/// If etching makes no progress, e.g. when the remaining edges form a cycle,
+/// the signature ends with the empty solution of the remaining maze.
+pub fn signature(x: &[[usize; 2]]) -> Vec<Vec<[usize; 2]>> {
+    use crate::solve_sorted;
+
+    let mut r = vec![];
+    let mut b: Vec<[usize; 2]> = x.into();
+    while !b.is_empty() {
+        let a = solve_sorted(b.clone());
+        let n = b.len();
+        initial(&a, &mut b);
+        let stuck = b.len() == n;
+        r.push(a);
+        if stuck {break}
+    }
+    r
+}
+
+/// Groups mazes into equivalence classes by their etch signature.
+///
+/// Returns lists of indices into `mazes`.
+/// The classes are ordered by their first member,
+/// and the indices within each class are in increasing order.
+pub fn equivalence_classes(mazes: &[Vec<[usize; 2]>]) -> Vec<Vec<usize>> {
+    use std::collections::HashMap;
+
+    let mut classes: Vec<Vec<usize>> = vec![];
+    let mut index: HashMap<Vec<Vec<[usize; 2]>>, usize> = HashMap::new();
+    for (i, x) in mazes.iter().enumerate() {
+        let s = signature(x);
+        if let Some(&k) = index.get(&s) {
+            classes[k].push(i);
+        } else {
+            index.insert(s, classes.len());
+            classes.push(vec![i]);
+        }
+    }
+    classes
+}
@@ -0,0 +1,97 @@
+//! Maximum flow networks, shared between modules.
+
+use std::collections::VecDeque;
+
+/// A capacity that is never the bottleneck.
+pub(crate) const INF: usize = usize::MAX / 2;
+
+/// A flow network using the Edmonds-Karp algorithm.
+///
+/// Arcs are stored in pairs, such that the reverse of arc `i` is `i ^ 1`.
+pub(crate) struct Network {
+    arcs: Vec<Vec<usize>>,
+    to: Vec<usize>,
+    cap: Vec<usize>,
+    residual: Vec<usize>,
+}
+
+impl Network {
+    /// Creates a network with `n` nodes and no arcs.
+    pub fn new(n: usize) -> Network {
+        Network {arcs: vec![vec![]; n], to: vec![], cap: vec![], residual: vec![]}
+    }
+
+    /// Adds an arc with capacity `c` and returns its index.
+    pub fn add(&mut self, a: usize, b: usize, c: usize) -> usize {
+        let i = self.to.len();
+        self.arcs[a].push(i);
+        self.to.push(b);
+        self.cap.push(c);
+        self.residual.push(c);
+        self.arcs[b].push(i + 1);
+        self.to.push(a);
+        self.cap.push(0);
+        self.residual.push(0);
+        i
+    }
+
+    /// Pushes the maximum flow from `s` to `t` and returns its value.
+    pub fn max_flow(&mut self, s: usize, t: usize) -> usize {
+        let mut total = 0;
+        loop {
+            // Breadth first search for the shortest augmenting path.
+            let mut parent: Vec<Option<usize>> = vec![None; self.arcs.len()];
+            let mut queue = VecDeque::new();
+            queue.push_back(s);
+            while let Some(a) = queue.pop_front() {
+                if a == t {break}
+                for &i in &self.arcs[a] {
+                    let b = self.to[i];
+                    if self.residual[i] > 0 && b != s && parent[b].is_none() {
+                        parent[b] = Some(i);
+                        queue.push_back(b);
+                    }
+                }
+            }
+            if parent[t].is_none() {return total}
+
+            let mut bottleneck = INF;
+            let mut b = t;
+            while let Some(i) = parent[b] {
+                bottleneck = bottleneck.min(self.residual[i]);
+                b = self.to[i ^ 1];
+            }
+            let mut b = t;
+            while let Some(i) = parent[b] {
+                self.residual[i] -= bottleneck;
+                self.residual[i ^ 1] += bottleneck;
+                b = self.to[i ^ 1];
+            }
+            total += bottleneck;
+        }
+    }
+
+    /// Returns the nodes reachable from `s` in the residual network.
+    ///
+    /// After computing the maximum flow, these nodes form the source side of a minimum cut.
+    pub fn source_side(&self, s: usize) -> Vec<bool> {
+        let mut visited = vec![false; self.arcs.len()];
+        visited[s] = true;
+        let mut stack = vec![s];
+        while let Some(a) = stack.pop() {
+            for &i in &self.arcs[a] {
+                let b = self.to[i];
+                if self.residual[i] > 0 && !visited[b] {
+                    visited[b] = true;
+                    stack.push(b);
+                }
+            }
+        }
+        visited
+    }
+
+    /// Returns the flow through arc `i`.
+    pub fn flow(&self, i: usize) -> usize {
+        self.cap[i] - self.residual[i]
+    }
+}
@@ -0,0 +1,78 @@
+//! ### Functor - Reachability preserving maps between mazes
+//!
+//! A map `f: usize -> usize` from the nodes of one maze to the nodes of another
+//! is a functor between their preorder categories when it preserves reachability:
+//! If `b` is reachable from `a`, then `f(b)` must be reachable from `f(a)`.
+//!
+//! It is sufficient to check this for every edge,
+//! since reachability is generated by composing edges.
+//! An edge `[a, b]` is allowed to map to an identity morphism `[f(a), f(a)]`.
+//!
+//! For example, collapsing the middle of a line:
+//!
+//! ```text
+//! 0 → 1 → 2 → 3
+//! f = {0: 0, 1: 1, 2: 1, 3: 2}
+//! 0 → 1 → 2
+//! ```
+
+use std::collections::HashMap;
+
+use crate::closure::Matrix;
+
+/// Checks whether `f` preserves reachability from `source` to `target`.
+///
+/// Returns the edges of `source` that are not preserved.
+/// If the list is empty, then `f` is a functor.
+pub fn check<F: Fn(usize) -> usize>(
+    source: &[[usize; 2]],
+    target: &[[usize; 2]],
+    f: F
+) -> Vec<[usize; 2]> {
+    let m = Matrix::reflexive(target);
+    source.iter().cloned().filter(|&[a, b]| !m.reaches(f(a), f(b))).collect()
+}
+
+/// Searches for an injective map that preserves reachability from `source` to `target`.
+///
+/// Any map sending every node to the same target node is trivially a functor,
+/// so the search is restricted to maps that send different nodes to different nodes.
+///
+/// Uses backtracking, which takes exponential time in the worst case.
+/// This is intended for small mazes.
+pub fn search(source: &[[usize; 2]], target: &[[usize; 2]]) -> Option<HashMap<usize, usize>> {
+    use crate::graph::nodes;
+
+    let m = Matrix::reflexive(target);
+    let from = nodes(source);
+    let mut f: HashMap<usize, usize> = HashMap::new();
+    let mut used = vec![false; m.nodes().len()];
+    if search_node(0, &from, source, &m, &mut f, &mut used) {Some(f)} else {None}
+}
+
+fn search_node(
+    i: usize,
+    from: &[usize],
+    source: &[[usize; 2]],
+    m: &Matrix,
+    f: &mut HashMap<usize, usize>,
+    used: &mut [bool],
+) -> bool {
+    if i == from.len() {return true}
+    let a = from[i];
+    for (j, &b) in m.nodes().iter().enumerate() {
+        if used[j] {continue}
+        f.insert(a, b);
+        let ok = source.iter().all(|&[c, d]| match (f.get(&c), f.get(&d)) {
+            (Some(&fc), Some(&fd)) => m.reaches(fc, fd),
+            _ => true,
+        });
+        if ok {
+            used[j] = true;
+            if search_node(i + 1, from, source, m, f, used) {return true}
+            used[j] = false;
+        }
+        f.remove(&a);
+    }
+    false
+}
@@ -0,0 +1,169 @@
+//! ### Gate - Mazes with linear resources shared between agents
+//!
+//! A gate is an edge that can be walked only once, across all agents.
+//! This is a linear resource in the sense of linear logic.
+//!
+//! The crate docs describe a magical gate that only lets the first person through.
+//! When Harry and Cedric both need the same gate, which of them reaches the Triwizard Cup
+//! depends on who arrives first. The solver does not model time,
+//! so the answer is reported as depending on timing instead of being guessed.
+//!
+//! For example, Harry starts at `1`, Cedric starts at `2`, and `[3, 4]` is a gate:
+//!
+//! ```text
+//! [1, 3], [2, 3], [4, 5]    gates: [3, 4]
+//! agents: [1, 5], [2, 5]
+//! ----------------------------------------
+//! combinations: [0], [1]
+//! Harry: depends on timing with [1]
+//! Cedric: depends on timing with [0]
+//! ```
+//!
+//! Agents are assumed to walk a path without repeating nodes,
+//! and an agent that can not reach its goal does not use any gate.
+//! Every path of every agent is enumerated, so this is intended for small mazes.
+
+/// Tells whether an agent reaches its goal.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Verdict {
+    /// The agent reaches its goal no matter which paths the other agents take.
+    Reaches,
+    /// The agent can not reach its goal, even when alone.
+    Never,
+    /// The agent reaches its goal or not depending on timing, which is not modeled.
+    ///
+    /// Contains the indices of agents that compete for the same gates.
+    DependsOnTiming(Vec<usize>),
+}
+
+/// The result of solving a maze with gates for multiple agents.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Report {
+    /// The combinations of agents that can reach their goals together,
+    /// given suitable timing.
+    ///
+    /// Every combination is a sorted list of agent indices.
+    /// Combinations are sorted, and the empty combination is not included.
+    pub combinations: Vec<Vec<usize>>,
+    /// The verdict of each agent.
+    pub verdicts: Vec<Verdict>,
+}
+
+/// Solves a maze with gates for multiple agents.
+///
+/// The edges in `edges` can be walked any number of times.
+/// Every edge in `gates` can be walked once in total.
+/// Each agent is a pair `[start, goal]`.
+///
+/// Every combination of agents is checked, so the number of agents is limited.
+/// Panics if there are 64 agents or more.
+pub fn solve(edges: &[[usize; 2]], gates: &[[usize; 2]], agents: &[[usize; 2]]) -> Report {
+    assert!(agents.len() < 64, "gate::solve supports at most 63 agents, got {}", agents.len());
+
+    // The gates used by every path of every agent.
+    let usages: Vec<Vec<Vec<usize>>> = agents.iter()
+        .map(|&[start, goal]| usages(edges, gates, start, goal)).collect();
+
+    let n = agents.len();
+    let mut combinations = vec![];
+    for mask in 1..1u64 << n {
+        let c: Vec<usize> = (0..n).filter(|&i| mask >> i & 1 == 1).collect();
+        let mut used = vec![false; gates.len()];
+        if feasible(&c, &usages, &mut used) {combinations.push(c)}
+    }
+    combinations.sort();
+
+    let mut verdicts = vec![];
+    for i in 0..n {
+        if usages[i].is_empty() {
+            verdicts.push(Verdict::Never);
+            continue;
+        }
+        let others: Vec<usize> = (0..n).filter(|&j| j != i && !usages[j].is_empty()).collect();
+        let mut used = vec![false; gates.len()];
+        if always_reaches(i, &others, &usages, &mut used) {
+            verdicts.push(Verdict::Reaches);
+        } else {
+            let competitors = others.into_iter().filter(|&j| {
+                usages[j].iter().any(|u| usages[i].iter().any(|v| u.iter().any(|g| v.contains(g))))
+            }).collect();
+            verdicts.push(Verdict::DependsOnTiming(competitors));
+        }
+    }
+
+    Report {combinations, verdicts}
+}
+
+/// Returns the sets of gates used by the paths from `start` to `goal`.
+fn usages(edges: &[[usize; 2]], gates: &[[usize; 2]], start: usize, goal: usize) -> Vec<Vec<usize>> {
+    fn walk(
+        a: usize,
+        goal: usize,
+        edges: &[[usize; 2]],
+        gates: &[[usize; 2]],
+        visited: &mut Vec<usize>,
+        used: &mut Vec<usize>,
+        r: &mut Vec<Vec<usize>>,
+    ) {
+        if a == goal {
+            let mut u = used.clone();
+            u.sort();
+            if !r.contains(&u) {r.push(u)}
+            return;
+        }
+        visited.push(a);
+        for &[b, c] in edges {
+            if b == a && !visited.contains(&c) {
+                walk(c, goal, edges, gates, visited, used, r);
+            }
+        }
+        for (i, &[b, c]) in gates.iter().enumerate() {
+            if b == a && !visited.contains(&c) {
+                used.push(i);
+                walk(c, goal, edges, gates, visited, used, r);
+                used.pop();
+            }
+        }
+        visited.pop();
+    }
+
+    let mut r = vec![];
+    walk(start, goal, edges, gates, &mut vec![], &mut vec![], &mut r);
+    r
+}
+
+/// Returns `true` if every agent in `c` can pick a path without sharing gates.
+fn feasible(c: &[usize], usages: &[Vec<Vec<usize>>], used: &mut [bool]) -> bool {
+    let (i, rest) = match c.split_first() {
+        None => return true,
+        Some(x) => x,
+    };
+    for u in &usages[*i] {
+        if u.iter().any(|&g| used[g]) {continue}
+        for &g in u {used[g] = true}
+        let ok = feasible(rest, usages, used);
+        for &g in u {used[g] = false}
+        if ok {return true}
+    }
+    false
+}
+
+/// Returns `true` if agent `i` has a path for every choice of paths of `others`.
+fn always_reaches(i: usize, others: &[usize], usages: &[Vec<Vec<usize>>], used: &mut [bool]) -> bool {
+    let (j, rest) = match others.split_first() {
+        None => return usages[i].iter().any(|u| u.iter().all(|&g| !used[g])),
+        Some(x) => x,
+    };
+    let mut moved = false;
+    for u in &usages[*j] {
+        // Paths that share gates with earlier agents are not possible.
+        if u.iter().any(|&g| used[g]) {continue}
+        moved = true;
+        for &g in u {used[g] = true}
+        let ok = always_reaches(i, rest, usages, used);
+        for &g in u {used[g] = false}
+        if !ok {return false}
+    }
+    // When every path is blocked, the agent does not use any gate.
+    moved || always_reaches(i, rest, usages, used)
+}
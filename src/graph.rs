@@ -0,0 +1,89 @@
+//! Graph helpers shared between modules.
+
+use std::collections::HashMap;
+
+/// Returns the nodes of a maze in increasing order.
+pub(crate) fn nodes(x: &[[usize; 2]]) -> Vec<usize> {
+    let mut r: Vec<usize> = x.iter().flat_map(|e| e.iter().cloned()).collect();
+    r.sort();
+    r.dedup();
+    r
+}
+
+/// Returns the successors of every node, without duplicates.
+///
+/// Nodes without outgoing edges are not included.
+pub(crate) fn successors(x: &[[usize; 2]]) -> HashMap<usize, Vec<usize>> {
+    let mut r: HashMap<usize, Vec<usize>> = HashMap::new();
+    for &[a, b] in x {
+        let s = r.entry(a).or_default();
+        if !s.contains(&b) {s.push(b)}
+    }
+    r
+}
+
+/// Finds the strongly connected components of a maze using Tarjan's algorithm.
+///
+/// Returns the components in topological order,
+/// such that edges between components always point forward,
+/// together with a map from every node to the index of its component.
+pub(crate) fn strongly_connected(x: &[[usize; 2]]) -> (Vec<Vec<usize>>, HashMap<usize, usize>) {
+    let succ = successors(x);
+    let mut index: HashMap<usize, usize> = HashMap::new();
+    let mut low: HashMap<usize, usize> = HashMap::new();
+    let mut on_stack: HashMap<usize, bool> = HashMap::new();
+    let mut stack: Vec<usize> = vec![];
+    let mut components: Vec<Vec<usize>> = vec![];
+    let empty = vec![];
+
+    for root in nodes(x) {
+        if index.contains_key(&root) {continue}
+        // Emulates recursion with an explicit stack of `(node, next successor)`.
+        let mut call: Vec<(usize, usize)> = vec![(root, 0)];
+        while let Some(&(a, i)) = call.last() {
+            if i == 0 && !index.contains_key(&a) {
+                let n = index.len();
+                index.insert(a, n);
+                low.insert(a, n);
+                stack.push(a);
+                on_stack.insert(a, true);
+            }
+            let s = succ.get(&a).unwrap_or(&empty);
+            if i < s.len() {
+                let b = s[i];
+                call.last_mut().unwrap().1 += 1;
+                if !index.contains_key(&b) {
+                    call.push((b, 0));
+                } else if on_stack[&b] {
+                    let l = low[&a].min(index[&b]);
+                    low.insert(a, l);
+                }
+                continue;
+            }
+            call.pop();
+            if let Some(&(parent, _)) = call.last() {
+                let l = low[&parent].min(low[&a]);
+                low.insert(parent, l);
+            }
+            if low[&a] == index[&a] {
+                let mut c = vec![];
+                loop {
+                    let b = stack.pop().unwrap();
+                    on_stack.insert(b, false);
+                    c.push(b);
+                    if b == a {break}
+                }
+                c.sort();
+                components.push(c);
+            }
+        }
+    }
+
+    // Tarjan's algorithm finds components in reverse topological order.
+    components.reverse();
+    let mut component_of = HashMap::new();
+    for (i, c) in components.iter().enumerate() {
+        for &a in c {component_of.insert(a, i);}
+    }
+    (components, component_of)
+}
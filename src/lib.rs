@@ -147,6 +147,89 @@ use std::collections::HashSet;
 pub mod etch;
 pub mod shapes;
 pub mod map;
+pub mod weighted;
+pub mod paths;
+pub mod scc;
+pub mod closure;
+pub mod category;
+pub mod functor;
+pub mod ops;
+pub mod sat;
+pub mod gate;
+pub mod agents;
+pub mod dominators;
+pub mod cut;
+pub mod disjoint;
+pub mod parallel;
+pub mod solution;
+pub mod limits;
+pub mod trace;
+pub mod stepper;
+pub mod rule;
+
+mod graph;
+mod flow;
+mod engine;
+
+/// Describes why a maze is not valid input.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Error {
+    /// The maze has no edges.
+    Empty,
+    /// The maze contains an edge from a node to itself.
+    SelfLoop(usize),
+    /// The maze contains the same edge more than once.
+    Duplicate([usize; 2]),
+    /// No initial node reaches a terminal node in the edges,
+    /// because every path leads into a cycle.
+    ///
+    /// Contains the sorted edges that cause the problem:
+    /// the edges on cycles for `try_solve`,
+    /// or the edges left when etching gets stuck for `etch::try_cardinality`.
+    CycleOnly(Vec<[usize; 2]>),
+    /// The dimensions of a map must be non-zero.
+    InvalidDimensions([usize; 2]),
+    /// The node is outside the map.
+    OutOfBounds {
+        /// The node.
+        node: usize,
+        /// The dimensions of the map.
+        dim: [usize; 2],
+    },
+    /// The edge does not connect neighbor cells on the map.
+    NotGrid([usize; 2]),
+    /// The solver stopped before completion.
+    Interrupted {
+        /// Why the solver stopped.
+        reason: limits::Interrupt,
+        /// The facts at the time the solver stopped.
+        ///
+        /// This is not a solution, since it might contain intermediate facts.
+        partial: Vec<[usize; 2]>,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Error::Empty => write!(f, "The maze has no edges"),
+            Error::SelfLoop(a) => write!(f, "The edge `[{}, {}]` is a self-loop", a, a),
+            Error::Duplicate([a, b]) => write!(f, "The edge `[{}, {}]` occurs more than once", a, b),
+            Error::CycleOnly(ref x) =>
+                write!(f, "No initial node reaches a terminal node, because of the edges {:?}", x),
+            Error::InvalidDimensions([w, h]) =>
+                write!(f, "The map dimensions `[{}, {}]` must be non-zero", w, h),
+            Error::OutOfBounds {node, dim: [w, h]} =>
+                write!(f, "The node `{}` is outside the map of dimensions `[{}, {}]`", node, w, h),
+            Error::NotGrid([a, b]) =>
+                write!(f, "The edge `[{}, {}]` does not connect neighbor cells", a, b),
+            Error::Interrupted {reason, ref partial} =>
+                write!(f, "The solver stopped ({:?}) with {} facts", reason, partial.len()),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
 
 /// Returns a list of edges that describes reachability
 /// from initial objects to terminal objects.
@@ -154,7 +237,105 @@ pub fn solve(edges: Vec<[usize; 2]>) -> Vec<[usize; 2]> {
     solve_minimum(edges, infer)
 }
 
+/// Solves a maze using a custom inference rule.
+///
+/// Using `rule::Transitive` gives the same output as `solve`.
+pub fn solve_with<R: rule::InferenceRule>(edges: Vec<[usize; 2]>, mut rule: R) -> Vec<[usize; 2]> {
+    let mut engine = engine::Engine::new(edges);
+    while engine.step(|cache, facts| rule.infer(cache, facts)) {}
+    engine.facts().into()
+}
+
+/// Returns a list of edges that describes reachability
+/// from initial objects to terminal objects, validating the input.
+///
+/// Returns an error if the maze is empty, contains self-loops or duplicate edges,
+/// or if the solution is empty because every path leads into a cycle.
+/// In the last case, the error contains the edges on cycles.
+pub fn try_solve(edges: Vec<[usize; 2]>) -> Result<Vec<[usize; 2]>, Error> {
+    use crate::graph::strongly_connected;
+
+    if edges.is_empty() {return Err(Error::Empty)}
+    validate(&edges)?;
+    let r = solve(edges.clone());
+    if r.is_empty() {
+        // Since there are no self-loops, edges within a component are on a cycle.
+        let (_, component_of) = strongly_connected(&edges);
+        let mut cyclic: Vec<[usize; 2]> = edges.into_iter()
+            .filter(|&[a, b]| component_of[&a] == component_of[&b]).collect();
+        cyclic.sort();
+        return Err(Error::CycleOnly(cyclic));
+    }
+    Ok(r)
+}
+
+/// Checks that there are no self-loops or duplicate edges.
+pub(crate) fn validate(edges: &[[usize; 2]]) -> Result<(), Error> {
+    let mut seen = HashSet::new();
+    for &[a, b] in edges {
+        if a == b {return Err(Error::SelfLoop(a))}
+        if !seen.insert([a, b]) {return Err(Error::Duplicate([a, b]))}
+    }
+    Ok(())
+}
+
+/// Returns a canonical list of edges that describes reachability
+/// from initial objects to terminal objects.
+///
+/// The output is sorted and without duplicates.
+/// Since the pairs only depend on which nodes reach each other,
+/// the same edges give the same output regardless of their order or repetition.
+pub fn solve_sorted(edges: Vec<[usize; 2]>) -> Vec<[usize; 2]> {
+    let mut r = solve(edges);
+    r.sort();
+    r.dedup();
+    r
+}
+
+/// Returns the nodes reachable from `start`, in increasing order.
+///
+/// Unlike `solve`, this works for any start node, not only initial nodes.
+/// The start node is included only when it lies on a cycle.
+pub fn reachable_from(edges: &[[usize; 2]], start: usize) -> Vec<usize> {
+    let succ = graph::successors(edges);
+    let mut visited = HashSet::new();
+    let mut stack = vec![start];
+    while let Some(a) = stack.pop() {
+        for &b in succ.get(&a).into_iter().flatten() {
+            if visited.insert(b) {stack.push(b)}
+        }
+    }
+    let mut r: Vec<usize> = visited.into_iter().collect();
+    r.sort();
+    r
+}
+
+/// Returns `true` if there is a path from `a` to `b`.
+///
+/// Unlike `solve`, this works for any pair of nodes, not only initial and terminal nodes.
+/// A node reaches itself only when it lies on a cycle.
+pub fn reaches(edges: &[[usize; 2]], a: usize, b: usize) -> bool {
+    let succ = graph::successors(edges);
+    let mut visited = HashSet::new();
+    let mut stack = vec![a];
+    while let Some(c) = stack.pop() {
+        for &d in succ.get(&c).into_iter().flatten() {
+            if d == b {return true}
+            if visited.insert(d) {stack.push(d)}
+        }
+    }
+    false
+}
+
 fn infer(cache: &HashSet<[usize; 2]>, facts: &[[usize; 2]]) -> Option<Inference<[usize; 2]>> {
+    if let Some([[a, _], [_, d]]) = propagate(cache, facts) {
+        return Some(Propagate([a, d]));
+    }
+    Some(ManyTrue {from: touching(facts)})
+}
+
+/// Finds two facts `[a, b]` and `[b, c]` such that `[a, c]` is new.
+fn propagate(cache: &HashSet<[usize; 2]>, facts: &[[usize; 2]]) -> Option<[[usize; 2]; 2]> {
     // Loop backwards to improve runtime performance a little,
     // since new inferences is often based on new facts.
     for ea in facts.iter().rev() {
@@ -164,22 +345,25 @@ fn infer(cache: &HashSet<[usize; 2]>, facts: &[[usize; 2]]) -> Option<Inference<
             if b == c {
                 let p = [*a, *d];
                 if !cache.contains(&p) {
-                    return Some(Propagate(p));
+                    return Some([*ea, *eb]);
                 }
             }
         }
     }
+    None
+}
 
+/// Returns the facts that touch another fact, once for every fact touched.
+fn touching(facts: &[[usize; 2]]) -> Vec<[usize; 2]> {
     let mut r = vec![];
     for ea in facts {
         let [a, b] = ea;
         for eb in facts {
             let [c, d] = eb;
             if c == b || a == d {
-                r.push(ea.clone());
+                r.push(*ea);
             }
         }
     }
-
-    return Some(ManyTrue {from: r});
+    r
 }
@@ -0,0 +1,104 @@
+//! ### Limits - Resource limits and cancellation for the solver
+//!
+//! The solver infers one fact at a time until it reaches a fixpoint.
+//! Since every step scans all pairs of facts, large mazes can take a long time.
+//!
+//! The options can limit the number of inference steps,
+//! set a wall-clock deadline, or cancel the solver from another thread.
+//! When a limit is hit, the solver returns the facts it had so far in an error,
+//! instead of running until completion.
+//!
+//! A progress callback is called after every step.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
+
+use crate::Error;
+
+/// Tells why the solver stopped before completion.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Interrupt {
+    /// The maximum number of inference steps was reached.
+    MaxSteps,
+    /// The deadline was passed.
+    Deadline,
+    /// The cancellation flag was set.
+    Cancelled,
+}
+
+/// Reports the progress of the solver.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct Progress {
+    /// The number of inference steps so far.
+    pub steps: usize,
+    /// The number of facts propagated so far.
+    pub propagated: usize,
+    /// The current number of facts.
+    pub facts: usize,
+}
+
+/// A callback that receives progress of the solver.
+pub type Callback = Box<dyn FnMut(&Progress)>;
+
+/// Stores options for the solver.
+#[derive(Default)]
+pub struct Options {
+    /// The maximum number of inference steps.
+    pub max_steps: Option<usize>,
+    /// The time when the solver stops.
+    pub deadline: Option<Instant>,
+    /// When set to `true`, the solver stops.
+    pub cancel: Option<Arc<AtomicBool>>,
+    /// Called after every inference step.
+    pub progress: Option<Callback>,
+}
+
+/// Solves a maze using the options.
+///
+/// Returns the same output as `solve` when it completes within limits.
+/// Otherwise, returns `Error::Interrupted` with the facts at the time the solver stopped.
+pub fn solve(edges: Vec<[usize; 2]>, mut options: Options) -> Result<Vec<[usize; 2]>, Error> {
+    use linear_solver::Inference;
+    use crate::engine::Engine;
+
+    let mut engine = Engine::new(edges);
+    let mut progress = Progress {steps: 0, propagated: 0, facts: engine.facts().len()};
+    loop {
+        let reason = if options.deadline.map(|d| Instant::now() >= d).unwrap_or(false) {
+            Some(Interrupt::Deadline)
+        } else if options.cancel.as_ref().map(|c| c.load(Ordering::SeqCst)).unwrap_or(false) {
+            Some(Interrupt::Cancelled)
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            return Err(Error::Interrupted {reason, partial: engine.facts().into()});
+        }
+
+        // The step limit is checked after inference,
+        // such that a maze solved in exactly `max_steps` steps completes.
+        let at_limit = options.max_steps.map(|n| progress.steps >= n).unwrap_or(false);
+        let mut limited = false;
+        let mut propagated = false;
+        let running = engine.step(|cache, facts| {
+            let x = crate::infer(cache, facts);
+            if at_limit && x.is_some() {
+                // Discard the inference to keep the facts of the last step.
+                limited = true;
+                return None;
+            }
+            propagated = matches!(x, Some(Inference::Propagate(_)));
+            x
+        });
+        if limited {
+            return Err(Error::Interrupted {reason: Interrupt::MaxSteps, partial: engine.facts().into()});
+        }
+        if !running {break}
+        progress.steps += 1;
+        if propagated {progress.propagated += 1}
+        progress.facts = engine.facts().len();
+        if let Some(ref mut f) = options.progress {f(&progress)}
+    }
+    Ok(engine.facts().into())
+}
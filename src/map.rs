@@ -239,6 +239,9 @@ impl Cell {
 ///
 /// This means that there is one cell variant that e.g. points left,
 /// another that represents a node, etc.
+///
+/// Edges between cells that are not neighbors are ignored.
+/// Panics if the dimensions are zero or a node is outside the map, see `try_map2`.
 pub fn map2(dim: [usize; 2], x: &[[usize; 2]]) -> Vec<Vec<Cell>> {
     let mut map = vec![vec![Cell::Empty; dim[0] * 2 - 1]; dim[1] * 2 - 1];
     for &[a, b] in x {
@@ -289,6 +292,28 @@ pub fn map2(dim: [usize; 2], x: &[[usize; 2]]) -> Vec<Vec<Cell>> {
     map
 }
 
+/// Creates a 2D map, validating the input.
+///
+/// Returns an error if the dimensions are zero, if a node is outside the map,
+/// or if an edge does not connect neighbor cells.
+pub fn try_map2(dim: [usize; 2], x: &[[usize; 2]]) -> Result<Vec<Vec<Cell>>, crate::Error> {
+    use crate::Error;
+
+    if dim[0] == 0 || dim[1] == 0 {return Err(Error::InvalidDimensions(dim))}
+    for &[a, b] in x {
+        for &node in &[a, b] {
+            if node >= dim[0] * dim[1] {return Err(Error::OutOfBounds {node, dim})}
+        }
+        if a == b {return Err(Error::SelfLoop(a))}
+        let pa = [a % dim[0], a / dim[0]];
+        let pb = [b % dim[0], b / dim[0]];
+        if pa[0].max(pb[0]) - pa[0].min(pb[0]) > 1 || pa[1].max(pb[1]) - pa[1].min(pb[1]) > 1 {
+            return Err(Error::NotGrid([a, b]));
+        }
+    }
+    Ok(map2(dim, x))
+}
+
 /// Generates a string that visualizes the map with unicode symbols.
 ///
 /// Here is an example of a map visualized:
@@ -318,13 +343,14 @@ pub fn map2(dim: [usize; 2], x: &[[usize; 2]]) -> Vec<Vec<Cell>> {
 /// ```
 ///
 /// A white arrow means that it crosses a bidirectional edge.
+#[allow(clippy::ptr_arg)]
 pub fn visualize(map: &Vec<Vec<Cell>>) -> String {
     let mut s = String::new();
     let h = map.len();
     let w = map[0].len();
-    for j in 0..h {
-        for i in 0..w {
-            s.push(match map[j][i] {
+    for (j, row) in map.iter().enumerate() {
+        for (i, cell) in row.iter().enumerate() {
+            s.push(match *cell {
                 Cell::Right => '→',
                 Cell::Left => '←',
                 Cell::Down => '↓',
@@ -0,0 +1,108 @@
+//! ### Ops - Algebraic operators to build large mazes from small ones
+//!
+//! - `coproduct` puts two mazes side by side
+//! - `product` connects copies of one maze along the edges of another, like a grid
+//! - `sequence` connects the terminal nodes of one maze to the initial nodes of another
+//! - `opposite` flips the direction of every edge
+//!
+//! Nodes of the second maze are renumbered by adding the `size` of the first maze.
+//!
+//! For example, `diag_rect([3, 3])` is the product of two `dir_line(3)`:
+//!
+//! ```text
+//! 0 → 1 → 2     0     0 → 1 → 2
+//!           ×   ↓  =  ↓   ↓   ↓
+//!               1     3 → 4 → 5
+//!               ↓     ↓   ↓   ↓
+//!               2     6 → 7 → 8
+//! ```
+
+/// Returns the number of node ids used by a maze, which is the largest node plus one.
+pub fn size(x: &[[usize; 2]]) -> usize {
+    x.iter().map(|&[a, b]| a.max(b) + 1).max().unwrap_or(0)
+}
+
+/// Adds `n` to every node.
+pub fn shift(x: &[[usize; 2]], n: usize) -> Vec<[usize; 2]> {
+    x.iter().map(|&[a, b]| [a + n, b + n]).collect()
+}
+
+/// Returns the disjoint union of two mazes.
+///
+/// The nodes of `b` are shifted by `size(a)`.
+pub fn coproduct(a: &[[usize; 2]], b: &[[usize; 2]]) -> Vec<[usize; 2]> {
+    let mut r: Vec<[usize; 2]> = a.into();
+    r.extend(shift(b, size(a)));
+    r
+}
+
+/// Returns the cartesian product of two mazes.
+///
+/// The node `(x, y)` is numbered `y * size(a) + x`.
+/// For every edge `[x0, x1]` in `a` and every node `y` in `b`,
+/// there is an edge from `(x0, y)` to `(x1, y)`.
+/// For every edge `[y0, y1]` in `b` and every node `x` in `a`,
+/// there is an edge from `(x, y0)` to `(x, y1)`.
+///
+/// Only nodes that have edges are included,
+/// so the product with a maze without edges is empty.
+pub fn product(a: &[[usize; 2]], b: &[[usize; 2]]) -> Vec<[usize; 2]> {
+    use crate::graph::nodes;
+
+    let w = size(a);
+    let mut r = vec![];
+    for y in nodes(b) {
+        for &[x0, x1] in a {
+            r.push([y * w + x0, y * w + x1]);
+        }
+    }
+    for x in nodes(a) {
+        for &[y0, y1] in b {
+            r.push([y0 * w + x, y1 * w + x]);
+        }
+    }
+    r
+}
+
+/// Returns the sequential composition of two mazes.
+///
+/// Every terminal node of `a` gets an edge to every initial node of `b`.
+/// The nodes of `b` are shifted by `size(a)`.
+pub fn sequence(a: &[[usize; 2]], b: &[[usize; 2]]) -> Vec<[usize; 2]> {
+    use crate::graph::nodes;
+
+    let n = size(a);
+    let b = shift(b, n);
+    let terminals: Vec<usize> = nodes(a).into_iter()
+        .filter(|&x| !a.iter().any(|e| e[0] == x)).collect();
+    let initials: Vec<usize> = nodes(&b).into_iter()
+        .filter(|&x| !b.iter().any(|e| e[1] == x)).collect();
+    let mut r: Vec<[usize; 2]> = a.into();
+    for &t in &terminals {
+        for &i in &initials {
+            r.push([t, i]);
+        }
+    }
+    r.extend(b);
+    r
+}
+
+/// Returns the opposite maze, where every edge is flipped.
+pub fn opposite(x: &[[usize; 2]]) -> Vec<[usize; 2]> {
+    x.iter().map(|&[a, b]| [b, a]).collect()
+}
+
+/// Composes two relations, like composing morphisms.
+///
+/// Returns `[a, c]` for every `[a, b]` in `x` and `[b, c]` in `y`, sorted and without duplicates.
+pub fn compose(x: &[[usize; 2]], y: &[[usize; 2]]) -> Vec<[usize; 2]> {
+    let mut r = vec![];
+    for &[a, b] in x {
+        for &[c, d] in y {
+            if b == c {r.push([a, d])}
+        }
+    }
+    r.sort();
+    r.dedup();
+    r
+}
@@ -0,0 +1,106 @@
+//! ### Parallel - Solving disconnected parts of a maze on multiple threads
+//!
+//! Large mazes often consist of many parts that are not connected to each other.
+//! Since no edge crosses between parts, each part can be solved independently,
+//! and the solution of the maze is the union of the solutions of the parts.
+//!
+//! The order of pairs from `solve` depends on the order the solver happens to infer facts,
+//! so the parallel solver returns the canonical output of `solve_sorted` instead.
+//!
+//! ```text
+//! [1, 2], [3, 4], [2, 5]
+//! ----------------------------------------
+//! components: [1, 2], [2, 5]    [3, 4]
+//! solution: [1, 5], [3, 4]
+//! ```
+
+use std::collections::HashMap;
+
+/// Splits a maze into weakly connected components.
+///
+/// Components are ordered by their first edge in the input,
+/// and edges within each component keep their order from the input.
+pub fn components(x: &[[usize; 2]]) -> Vec<Vec<[usize; 2]>> {
+    fn find(parent: &mut HashMap<usize, usize>, a: usize) -> usize {
+        let mut root = a;
+        loop {
+            let p = *parent.entry(root).or_insert(root);
+            if p == root {break}
+            root = p;
+        }
+        // Path compression.
+        let mut b = a;
+        while b != root {
+            let next = parent[&b];
+            parent.insert(b, root);
+            b = next;
+        }
+        root
+    }
+
+    // Union by size keeps the trees shallow.
+    let mut parent: HashMap<usize, usize> = HashMap::new();
+    let mut size: HashMap<usize, usize> = HashMap::new();
+    for &[a, b] in x {
+        let (ra, rb) = (find(&mut parent, a), find(&mut parent, b));
+        if ra == rb {continue}
+        let (sa, sb) = (*size.get(&ra).unwrap_or(&1), *size.get(&rb).unwrap_or(&1));
+        let (small, large) = if sa < sb {(ra, rb)} else {(rb, ra)};
+        parent.insert(small, large);
+        size.insert(large, sa + sb);
+    }
+
+    let mut index: HashMap<usize, usize> = HashMap::new();
+    let mut r: Vec<Vec<[usize; 2]>> = vec![];
+    for &[a, b] in x {
+        let root = find(&mut parent, a);
+        let i = *index.entry(root).or_insert_with(|| {
+            r.push(vec![]);
+            r.len() - 1
+        });
+        r[i].push([a, b]);
+    }
+    r
+}
+
+/// Solves every weakly connected component on a separate thread.
+///
+/// Uses as many threads as the available parallelism of the system.
+///
+/// The output is canonical, identical to `solve_sorted`.
+pub fn solve(x: &[[usize; 2]]) -> Vec<[usize; 2]> {
+    use std::thread;
+
+    let threads = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    solve_with_threads(x, threads)
+}
+
+/// Solves every weakly connected component using up to `threads` threads.
+///
+/// The output is canonical, identical to `solve_sorted`,
+/// and does not depend on the number of threads.
+pub fn solve_with_threads(x: &[[usize; 2]], threads: usize) -> Vec<[usize; 2]> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    let parts = components(x);
+    let next = AtomicUsize::new(0);
+    let solved: Vec<Vec<[usize; 2]>> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads.max(1).min(parts.len())).map(|_| {
+            scope.spawn(|| {
+                let mut r = vec![];
+                loop {
+                    let i = next.fetch_add(1, Ordering::SeqCst);
+                    if i >= parts.len() {break}
+                    r.push(crate::solve(parts[i].clone()));
+                }
+                r
+            })
+        }).collect();
+        workers.into_iter().flat_map(|w| w.join().unwrap()).collect()
+    });
+    let mut r: Vec<[usize; 2]> = solved.into_iter().flatten().collect();
+    r.sort();
+    r.dedup();
+    r
+}
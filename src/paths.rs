@@ -0,0 +1,97 @@
+//! ### Paths - Counting distinct paths from initial to terminal nodes
+//!
+//! The solver tells whether a terminal node is reachable from an initial node.
+//! For robustness analysis, it is useful to know how many routes lead there.
+//! A pair that is reachable through many distinct paths is hard to block.
+//!
+//! For example:
+//!
+//! ```text
+//! [1, 2], [1, 3], [2, 4], [3, 4]
+//! ------------------------------
+//! [1, 4] 2 paths
+//! ```
+//!
+//! When a path passes through a cycle, one can walk around the cycle any number of times.
+//! The number of routes is then unbounded.
+
+use std::collections::HashMap;
+
+/// The number of distinct paths between two nodes.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Count {
+    /// There is a finite number of paths.
+    Finite(u64),
+    /// There are more paths than fits in `u64`.
+    Saturated,
+    /// Some path passes through a cycle, so there are infinitely many routes.
+    Unbounded,
+}
+
+/// Counts the distinct paths for every pair in the solution of a maze.
+///
+/// Duplicate edges are counted once.
+///
+/// Returns the output of `solve` with the number of paths of each pair.
+pub fn count(x: &[[usize; 2]]) -> Vec<([usize; 2], Count)> {
+    use crate::solve;
+    use crate::graph::strongly_connected;
+
+    let (components, component_of) = strongly_connected(x);
+    let mut cyclic = vec![false; components.len()];
+    for c in &components {
+        if c.len() > 1 {cyclic[component_of[&c[0]]] = true}
+    }
+    let mut out: Vec<Vec<usize>> = vec![vec![]; components.len()];
+    let mut seen = std::collections::HashSet::new();
+    for &[a, b] in x {
+        if a == b {
+            cyclic[component_of[&a]] = true;
+        } else if component_of[&a] != component_of[&b] && seen.insert([a, b]) {
+            out[component_of[&a]].push(component_of[&b]);
+        }
+    }
+
+    let mut from: HashMap<usize, Vec<Option<Partial>>> = HashMap::new();
+    let mut r = vec![];
+    for [a, b] in solve(x.into()) {
+        let counts = from.entry(a).or_insert_with(|| {
+            // Propagate counts in topological order.
+            let mut counts: Vec<Option<Partial>> = vec![None; components.len()];
+            let start = component_of[&a];
+            counts[start] = Some(Partial {count: 1, saturated: false, cyclic: false});
+            for i in start..components.len() {
+                if let Some(p) = counts[i] {
+                    let cyclic = p.cyclic || cyclic[i];
+                    for &j in &out[i] {
+                        let q = counts[j].unwrap_or(Partial {count: 0, saturated: false, cyclic: false});
+                        let sum = q.count.checked_add(p.count);
+                        counts[j] = Some(Partial {
+                            count: sum.unwrap_or(u64::MAX),
+                            saturated: p.saturated || q.saturated || sum.is_none(),
+                            cyclic: cyclic || q.cyclic,
+                        });
+                    }
+                }
+            }
+            counts
+        });
+        let count = match counts[component_of[&b]] {
+            Some(Partial {cyclic: true, ..}) => Count::Unbounded,
+            Some(Partial {saturated: true, ..}) => Count::Saturated,
+            Some(Partial {count, ..}) => Count::Finite(count),
+            None => Count::Finite(0),
+        };
+        r.push(([a, b], count));
+    }
+    r
+}
+
+/// Number of paths found so far to some component.
+#[derive(Clone, Copy)]
+struct Partial {
+    count: u64,
+    saturated: bool,
+    // Whether some path passes through a cycle.
+    cyclic: bool,
+}
@@ -0,0 +1,45 @@
+//! ### Rule - Pluggable inference rules
+//!
+//! The solver uses a single inference rule, `Transitive`:
+//!
+//! 1. Propagate `[a, b]` and `[b, c]` into `[a, c]`, until no new facts can be inferred
+//! 2. Remove every fact that touches another fact
+//!
+//! Other reduction semantics can be studied by implementing `InferenceRule`,
+//! e.g. keeping some waypoints, treating some nodes as transparent,
+//! or propagating only within a budget of hops.
+//! The rule returns an `Inference` from `linear_solver`, which tells the solver
+//! which facts to add and which to remove.
+//!
+//! The solver stops when the rule returns `None`,
+//! or when the facts repeat in a cycle, picking the smallest set of facts in the cycle.
+
+use std::collections::HashSet;
+
+pub use linear_solver::Inference;
+
+/// An inference rule for the solver.
+pub trait InferenceRule {
+    /// Infers from the current facts.
+    ///
+    /// The cache contains the same facts as `facts`, for fast lookup.
+    fn infer(&mut self, cache: &HashSet<[usize; 2]>, facts: &[[usize; 2]]) -> Option<Inference<[usize; 2]>>;
+}
+
+/// The default rule, used by `solve`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+pub struct Transitive;
+
+impl InferenceRule for Transitive {
+    fn infer(&mut self, cache: &HashSet<[usize; 2]>, facts: &[[usize; 2]]) -> Option<Inference<[usize; 2]>> {
+        crate::infer(cache, facts)
+    }
+}
+
+impl<F> InferenceRule for F
+    where F: FnMut(&HashSet<[usize; 2]>, &[[usize; 2]]) -> Option<Inference<[usize; 2]>>
+{
+    fn infer(&mut self, cache: &HashSet<[usize; 2]>, facts: &[[usize; 2]]) -> Option<Inference<[usize; 2]>> {
+        self(cache, facts)
+    }
+}
@@ -0,0 +1,87 @@
+//! ### Sat - Searching for edges that satisfy a higher order maze problem
+//!
+//! A higher order maze problem is a Boolean formula over atoms `A -> B`,
+//! where `A -> B` is true when `[A, B]` is in the solution of the maze.
+//!
+//! Instead of evaluating the formula on a fixed maze,
+//! some edges can be marked as optional, and the solver searches for
+//! a choice of optional edges that makes the formula true.
+//!
+//! For example, Harry starts at `1`, Cedric starts at `2` and the Triwizard Cup is at `5`.
+//! The edge `[3, 4]`, where Harry helps Cedric, is optional:
+//!
+//! ```text
+//! [1, 4], [2, 3], [4, 5]    optional: [3, 4]
+//! (1 -> 5) ∧ ¬(2 -> 5)
+//! ----------------------------------------
+//! [3, 4] = false
+//! ```
+
+/// A Boolean formula over reachability atoms.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Expr {
+    /// Always true.
+    True,
+    /// Always false.
+    False,
+    /// `A -> B`, true when `[A, B]` is in the solution.
+    Reach(usize, usize),
+    /// Logical NOT.
+    Not(Box<Expr>),
+    /// Logical AND.
+    And(Box<Expr>, Box<Expr>),
+    /// Logical OR.
+    Or(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Evaluates the formula using the solution of a maze.
+    pub fn eval(&self, solution: &[[usize; 2]]) -> bool {
+        match *self {
+            Expr::True => true,
+            Expr::False => false,
+            Expr::Reach(a, b) => solution.contains(&[a, b]),
+            Expr::Not(ref a) => !a.eval(solution),
+            Expr::And(ref a, ref b) => a.eval(solution) && b.eval(solution),
+            Expr::Or(ref a, ref b) => a.eval(solution) || b.eval(solution),
+        }
+    }
+}
+
+/// Searches for a choice of optional edges that satisfies the formula.
+///
+/// The edges in `edges` are always present.
+/// Returns `Some(choice)` where `choice[i]` tells whether `optional[i]` is included,
+/// or `None` if no choice of optional edges satisfies the formula.
+///
+/// Enumerates every choice of optional edges, without pruning,
+/// and calls the solver once per choice until the formula is satisfied.
+/// Choices that exclude optional edges are tried before choices that include them.
+/// This takes exponential time in the number of optional edges.
+pub fn find(edges: &[[usize; 2]], optional: &[[usize; 2]], formula: &Expr) -> Option<Vec<bool>> {
+    let mut choice = Vec::with_capacity(optional.len());
+    if search(edges, optional, formula, &mut choice) {Some(choice)} else {None}
+}
+
+fn search(
+    edges: &[[usize; 2]],
+    optional: &[[usize; 2]],
+    formula: &Expr,
+    choice: &mut Vec<bool>
+) -> bool {
+    use crate::solve;
+
+    if choice.len() == optional.len() {
+        let mut x: Vec<[usize; 2]> = edges.into();
+        for (&e, &c) in optional.iter().zip(choice.iter()) {
+            if c {x.push(e)}
+        }
+        return formula.eval(&solve(x));
+    }
+    for &c in &[false, true] {
+        choice.push(c);
+        if search(edges, optional, formula, choice) {return true}
+        choice.pop();
+    }
+    false
+}
@@ -0,0 +1,59 @@
+//! ### SCC - Condensation of strongly connected components
+//!
+//! The solver removes cycles from the output,
+//! such that a maze where every node lies on a cycle has an empty solution.
+//!
+//! A strongly connected component is a set of nodes that all reach each other.
+//! By collapsing every component into a single node, one gets a maze without cycles,
+//! called the "condensation" of the maze.
+//! Solving the condensation gives meaningful reachability even when there are no initial nodes.
+//!
+//! For example, using `a` for the component `{4, 5, 6, 7}`:
+//!
+//! ```text
+//! [1, 4], [3, 6], [4, 5], [5, 6], [6, 7], [7, 4]
+//! ----------------------------------------------
+//! [1, a], [3, a]
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+/// Stores the condensation of a maze.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Condensation {
+    /// The strongly connected components, in topological order.
+    ///
+    /// The nodes of every component are sorted.
+    pub components: Vec<Vec<usize>>,
+    /// The edges between components, using component indices.
+    ///
+    /// Every edge points from a lower index to a higher index.
+    pub edges: Vec<[usize; 2]>,
+    /// Maps every node in the original maze to the index of its component.
+    pub component_of: HashMap<usize, usize>,
+}
+
+impl Condensation {
+    /// Solves the condensation.
+    ///
+    /// Returns pairs of component indices.
+    pub fn solve(&self) -> Vec<[usize; 2]> {
+        crate::solve(self.edges.clone())
+    }
+}
+
+/// Condenses the strongly connected components of a maze.
+pub fn condense(x: &[[usize; 2]]) -> Condensation {
+    use crate::graph::strongly_connected;
+
+    let (components, component_of) = strongly_connected(x);
+    let mut edges = vec![];
+    let mut seen = HashSet::new();
+    for &[a, b] in x {
+        let e = [component_of[&a], component_of[&b]];
+        if e[0] != e[1] && seen.insert(e) {
+            edges.push(e);
+        }
+    }
+    Condensation {components, edges, component_of}
+}
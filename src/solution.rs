@@ -0,0 +1,102 @@
+//! ### Solution - Indexed lookups in the output of the solver
+//!
+//! The output of `solve` is a list of pairs.
+//! A `Solution` indexes the pairs by initial and terminal node,
+//! such that questions like "which terminal nodes are reachable from `a`?"
+//! can be answered without scanning the list.
+//!
+//! Solutions can be compared with set operations,
+//! e.g. to find which pairs are lost in an alternative timeline:
+//!
+//! ```text
+//! [1, 4], [2, 3], [3, 4], [4, 5]  =>  [1, 5], [2, 5]
+//! [1, 4], [2, 3], [4, 5]          =>  [1, 5], [2, 3]
+//! ----------------------------------------
+//! difference: [2, 5]
+//! ```
+
+use std::collections::HashMap;
+
+/// Stores the pairs of a solution with indexed lookups.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Solution {
+    pairs: Vec<[usize; 2]>,
+    initials: Vec<usize>,
+    terminals: Vec<usize>,
+    targets: HashMap<usize, Vec<usize>>,
+    sources: HashMap<usize, Vec<usize>>,
+}
+
+impl Solution {
+    /// Solves a maze.
+    pub fn new(edges: Vec<[usize; 2]>) -> Solution {
+        Solution::from_pairs(crate::solve(edges))
+    }
+
+    /// Creates a solution from pairs, e.g. the output of `solve`.
+    pub fn from_pairs(mut pairs: Vec<[usize; 2]>) -> Solution {
+        pairs.sort();
+        pairs.dedup();
+        let mut targets: HashMap<usize, Vec<usize>> = HashMap::new();
+        let mut sources: HashMap<usize, Vec<usize>> = HashMap::new();
+        for &[a, b] in &pairs {
+            targets.entry(a).or_default().push(b);
+            sources.entry(b).or_default().push(a);
+        }
+        for s in sources.values_mut() {s.sort()}
+        let mut initials: Vec<usize> = targets.keys().cloned().collect();
+        initials.sort();
+        let mut terminals: Vec<usize> = sources.keys().cloned().collect();
+        terminals.sort();
+        Solution {pairs, initials, terminals, targets, sources}
+    }
+
+    /// Returns the pairs, sorted and without duplicates.
+    pub fn pairs(&self) -> &[[usize; 2]] {&self.pairs}
+
+    /// Returns the number of pairs.
+    pub fn len(&self) -> usize {self.pairs.len()}
+
+    /// Returns `true` if there are no pairs.
+    pub fn is_empty(&self) -> bool {self.pairs.is_empty()}
+
+    /// Returns the initial nodes in the solution, sorted.
+    pub fn initials(&self) -> &[usize] {&self.initials}
+
+    /// Returns the terminal nodes in the solution, sorted.
+    pub fn terminals(&self) -> &[usize] {&self.terminals}
+
+    /// Returns the terminal nodes reachable from `a`, sorted.
+    pub fn targets_of(&self, a: usize) -> &[usize] {
+        self.targets.get(&a).map(|x| &x[..]).unwrap_or(&[])
+    }
+
+    /// Returns the initial nodes that reach `b`, sorted.
+    pub fn sources_of(&self, b: usize) -> &[usize] {
+        self.sources.get(&b).map(|x| &x[..]).unwrap_or(&[])
+    }
+
+    /// Returns `true` if the solution contains the pair `[a, b]`.
+    pub fn contains(&self, a: usize, b: usize) -> bool {
+        self.targets_of(a).binary_search(&b).is_ok()
+    }
+
+    /// Returns the pairs that are in either solution.
+    pub fn union(&self, other: &Solution) -> Solution {
+        let mut pairs = self.pairs.clone();
+        pairs.extend_from_slice(&other.pairs);
+        Solution::from_pairs(pairs)
+    }
+
+    /// Returns the pairs that are in both solutions.
+    pub fn intersection(&self, other: &Solution) -> Solution {
+        Solution::from_pairs(self.pairs.iter().cloned()
+            .filter(|&[a, b]| other.contains(a, b)).collect())
+    }
+
+    /// Returns the pairs that are in this solution, but not in the other.
+    pub fn difference(&self, other: &Solution) -> Solution {
+        Solution::from_pairs(self.pairs.iter().cloned()
+            .filter(|&[a, b]| !other.contains(a, b)).collect())
+    }
+}
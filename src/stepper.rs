@@ -0,0 +1,60 @@
+//! ### Stepper - Advancing the solver one inference at a time
+//!
+//! A stepper keeps the state of the solver between inferences,
+//! such that a teaching tool or a debugger can show every intermediate set of facts.
+//!
+//! When the maze is a grid, the facts can be rendered with `map::visualize`.
+
+use crate::engine::Engine;
+use crate::trace::Step;
+use crate::Error;
+
+/// Solves a maze one inference at a time.
+pub struct Stepper {
+    engine: Engine,
+}
+
+impl Stepper {
+    /// Creates a new stepper with the edges of a maze as starting facts.
+    pub fn new(edges: Vec<[usize; 2]>) -> Stepper {
+        Stepper {engine: Engine::new(edges)}
+    }
+
+    /// Performs one inference.
+    ///
+    /// Returns `None` when the solver is finished.
+    /// The current facts are then the same as the output of `solve`.
+    pub fn step(&mut self) -> Option<Step> {
+        let mut step = None;
+        self.engine.step(|cache, facts| {
+            let (s, inference) = crate::trace::infer(cache, facts);
+            step = Some(s);
+            Some(inference)
+        });
+        step
+    }
+
+    /// Returns the current facts.
+    pub fn current_facts(&self) -> &[[usize; 2]] {self.engine.facts()}
+
+    /// Visualizes the current facts on a map, see `map::visualize`.
+    ///
+    /// Facts between cells that are not neighbors, such as most propagated facts,
+    /// and self-loops, which are propagated when the maze has a cycle, are not shown.
+    /// Returns an error if the dimensions are zero or a node of any fact is outside the map.
+    pub fn visualize(&self, dim: [usize; 2]) -> Result<String, Error> {
+        use crate::map::{try_map2, visualize};
+
+        if dim[0] == 0 || dim[1] == 0 {return Err(Error::InvalidDimensions(dim))}
+        let w = dim[0];
+        for &node in self.current_facts().iter().flatten() {
+            if node >= w * dim[1] {return Err(Error::OutOfBounds {node, dim})}
+        }
+        let facts: Vec<[usize; 2]> = self.current_facts().iter().cloned().filter(|&[a, b]| {
+            let (pa, pb) = ([a % w, a / w], [b % w, b / w]);
+            a != b &&
+            pa[0].max(pb[0]) - pa[0].min(pb[0]) <= 1 && pa[1].max(pb[1]) - pa[1].min(pb[1]) <= 1
+        }).collect();
+        Ok(visualize(&try_map2(dim, &facts)?))
+    }
+}
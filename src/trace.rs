@@ -0,0 +1,141 @@
+//! ### Trace - Recording the inference steps of the solver
+//!
+//! The solver works in two phases:
+//!
+//! 1. Propagate: Combine two facts `[a, b]` and `[b, c]` into a new fact `[a, c]`,
+//!    until no new facts can be inferred
+//! 2. Remove: Remove every fact that touches another fact,
+//!    which leaves pairs of initial and terminal nodes
+//!
+//! The last steps remove nothing, which is how the solver detects that it has reached a fixpoint.
+//!
+//! For example:
+//!
+//! ```text
+//! start: [1, 2], [2, 3]
+//! propagate: [1, 2], [2, 3] => [1, 3]
+//! remove: [1, 2], [2, 3]
+//! remove:
+//! remove:
+//! solution: [1, 3]
+//! ```
+//!
+//! A trace can be exported as text, using `Display`, or as JSON, using `Trace::to_json`.
+
+use std::fmt;
+
+/// An inference step of the solver.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Step {
+    /// Combines two facts `[a, b]` and `[b, c]` into a new fact `[a, c]`.
+    Propagate {
+        /// The facts that were combined.
+        from: [[usize; 2]; 2],
+        /// The new fact.
+        to: [usize; 2],
+    },
+    /// Removes facts that touch another fact.
+    Remove {
+        /// The facts that were removed, without repetition.
+        from: Vec<[usize; 2]>,
+    },
+}
+
+/// Stores every inference step from the start to the solution.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Trace {
+    /// The starting facts.
+    pub start: Vec<[usize; 2]>,
+    /// The inference steps, in order.
+    pub steps: Vec<Step>,
+    /// The solution, the same as returned by `solve`.
+    pub solution: Vec<[usize; 2]>,
+}
+
+/// Solves a maze while recording every inference step.
+pub fn solve(edges: Vec<[usize; 2]>) -> Trace {
+    use crate::engine::Engine;
+
+    let mut engine = Engine::new(edges.clone());
+    let mut steps = vec![];
+    while engine.step(|cache, facts| {
+        let (step, inference) = infer(cache, facts);
+        steps.push(step);
+        Some(inference)
+    }) {}
+    Trace {start: edges, steps, solution: engine.facts().into()}
+}
+
+/// Infers like the solver, but also explains the inference.
+pub(crate) fn infer(
+    cache: &std::collections::HashSet<[usize; 2]>,
+    facts: &[[usize; 2]]
+) -> (Step, linear_solver::Inference<[usize; 2]>) {
+    use linear_solver::Inference;
+
+    if let Some(from) = crate::propagate(cache, facts) {
+        let to = [from[0][0], from[1][1]];
+        return (Step::Propagate {from, to}, Inference::Propagate(to));
+    }
+    let consumed = crate::touching(facts);
+    let mut from: Vec<[usize; 2]> = vec![];
+    for &e in &consumed {
+        if !from.contains(&e) {from.push(e)}
+    }
+    (Step::Remove {from}, Inference::ManyTrue {from: consumed})
+}
+
+fn write_list(w: &mut dyn fmt::Write, x: &[[usize; 2]]) -> fmt::Result {
+    for (i, e) in x.iter().enumerate() {
+        if i > 0 {write!(w, ", ")?}
+        write!(w, "{:?}", e)?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Step::Propagate {from, to} => write!(f, "propagate: {:?}, {:?} => {:?}", from[0], from[1], to),
+            Step::Remove {ref from} => {
+                write!(f, "remove:")?;
+                if !from.is_empty() {write!(f, " ")?}
+                write_list(f, from)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "start: ")?;
+        write_list(f, &self.start)?;
+        writeln!(f)?;
+        for step in &self.steps {
+            writeln!(f, "{}", step)?;
+        }
+        write!(f, "solution: ")?;
+        write_list(f, &self.solution)
+    }
+}
+
+impl Trace {
+    /// Exports the trace as JSON.
+    ///
+    /// Every edge is an array of two numbers.
+    /// Steps are objects with either a `propagate` or a `remove` field.
+    pub fn to_json(&self) -> String {
+        fn list(x: &[[usize; 2]]) -> String {
+            let items: Vec<String> = x.iter().map(|e| format!("[{},{}]", e[0], e[1])).collect();
+            format!("[{}]", items.join(","))
+        }
+
+        let steps: Vec<String> = self.steps.iter().map(|step| match *step {
+            Step::Propagate {from, to} =>
+                format!("{{\"propagate\":{{\"from\":{},\"to\":[{},{}]}}}}", list(&from), to[0], to[1]),
+            Step::Remove {ref from} => format!("{{\"remove\":{}}}", list(from)),
+        }).collect();
+        format!("{{\"start\":{},\"steps\":[{}],\"solution\":{}}}",
+            list(&self.start), steps.join(","), list(&self.solution))
+    }
+}
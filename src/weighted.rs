@@ -0,0 +1,85 @@
+//! ### Weighted - Shortest distances between initial and terminal nodes
+//!
+//! The solver tells whether a terminal node is reachable from an initial node,
+//! but not how long it takes to get there.
+//! By assigning a weight to every edge, e.g. the time it takes to walk a corridor,
+//! one can compare the shortest distances of the pairs in the solution.
+//!
+//! For example, when Harry starts at `1` and Cedric starts at `2`:
+//!
+//! ```text
+//! [1, 4], [2, 3], [3, 4], [4, 5]
+//! 3,      1,      1,      2
+//! ----------------------------------------
+//! [1, 5] distance 5 hops 2
+//! [2, 5] distance 4 hops 3
+//! ```
+//!
+//! Cedric reaches the Triwizard Cup first, even if he has to walk more corridors.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+/// Stores the shortest distance from an initial node to a terminal node.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct Distance {
+    /// The pair of initial and terminal node.
+    pub pair: [usize; 2],
+    /// The sum of weights along the shortest path.
+    pub distance: u64,
+    /// The number of edges along the shortest path.
+    ///
+    /// When there are several shortest paths, this is the least number of edges.
+    pub hops: usize,
+}
+
+/// Solves a maze with weighted edges.
+///
+/// The weight of `edges[i]` is `weights[i]`.
+///
+/// Returns the output of `solve` together with the shortest distance of each pair,
+/// in the same order.
+/// Distances that do not fit in `u64` saturate at `u64::MAX`.
+/// Panics if the number of weights differs from the number of edges.
+pub fn solve(edges: &[[usize; 2]], weights: &[u64]) -> (Vec<[usize; 2]>, Vec<Distance>) {
+    use crate::solve;
+
+    assert_eq!(edges.len(), weights.len(), "Expected one weight per edge");
+
+    let mut adj: HashMap<usize, Vec<(usize, u64)>> = HashMap::new();
+    for (&[a, b], &w) in edges.iter().zip(weights) {
+        adj.entry(a).or_default().push((b, w));
+    }
+
+    let pairs = solve(edges.into());
+    let mut from: HashMap<usize, HashMap<usize, (u64, usize)>> = HashMap::new();
+    let mut r = Vec::with_capacity(pairs.len());
+    for &[a, b] in &pairs {
+        let dist = from.entry(a).or_insert_with(|| shortest(&adj, a));
+        let (distance, hops) = dist[&b];
+        r.push(Distance {pair: [a, b], distance, hops});
+    }
+    (pairs, r)
+}
+
+/// Dijkstra's algorithm, ordered by distance first and number of hops second.
+fn shortest(
+    adj: &HashMap<usize, Vec<(usize, u64)>>,
+    start: usize
+) -> HashMap<usize, (u64, usize)> {
+    let mut dist: HashMap<usize, (u64, usize)> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    dist.insert(start, (0, 0));
+    queue.push(Reverse((0, 0, start)));
+    while let Some(Reverse((d, h, a))) = queue.pop() {
+        if dist[&a] < (d, h) {continue}
+        for &(b, w) in adj.get(&a).into_iter().flatten() {
+            let next = (d.saturating_add(w), h + 1);
+            if dist.get(&b).map(|&x| next < x).unwrap_or(true) {
+                dist.insert(b, next);
+                queue.push(Reverse((next.0, next.1, b)));
+            }
+        }
+    }
+    dist
+}
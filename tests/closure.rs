@@ -0,0 +1,37 @@
+extern crate reachability_solver;
+
+use reachability_solver::shapes;
+use reachability_solver::closure::{closure, transitive_reduction};
+
+/// Checks that the reduction keeps reachability and can not be reduced further.
+fn check(x: &[[usize; 2]]) {
+    let y = transitive_reduction(x);
+    assert_eq!(closure(&y), closure(x), "{:?}", x);
+    assert_eq!(transitive_reduction(&y), y, "{:?}", x);
+}
+
+#[test]
+fn reduce_dir_line() {
+    for n in 0..6 {
+        check(&shapes::dir_line(n));
+        check(&closure(&shapes::dir_line(n)));
+    }
+}
+
+#[test]
+fn reduce_diag_rect() {
+    for w in 1..4 {
+        for h in 1..4 {
+            let x = shapes::diag_rect([w, h]);
+            check(&x);
+            check(&closure(&x));
+        }
+    }
+}
+
+#[test]
+fn reduce_cycles() {
+    // 1 ⇄ 2 → 3 ⇄ 4
+    //  \_______↗
+    check(&[[1, 2], [2, 1], [2, 3], [3, 4], [4, 3], [1, 3], [5, 5]]);
+}
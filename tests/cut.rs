@@ -0,0 +1,77 @@
+extern crate reachability_solver;
+
+use reachability_solver::{cut, disjoint, solve, shapes};
+use reachability_solver::cut::{EdgeCut, NodeCut};
+
+/// Xorshift random number generator, to keep the tests free of dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+/// Generates a random maze, which might contain cycles.
+fn random_maze(rng: &mut Rng) -> Vec<[usize; 2]> {
+    let n = 2 + rng.next(6);
+    let m = rng.next(3 * n);
+    (0..m).map(|_| [rng.next(n), rng.next(n)]).filter(|e| e[0] != e[1]).collect()
+}
+
+#[test]
+fn module_doc_example() {
+    //     2
+    //   ↗︎   ↘︎
+    // 1       4 → 5
+    //   ↘︎   ↗︎
+    //     3
+    let x = vec![[1, 2], [1, 3], [2, 4], [3, 4], [4, 5]];
+    assert_eq!(cut::edge_cut(&x, 1, 5), EdgeCut {edges: vec![[4, 5]], verified: true});
+    assert_eq!(cut::node_cut(&x, 1, 5), Some(NodeCut {nodes: vec![4], verified: true}));
+}
+
+#[test]
+fn all_pairs() {
+    // 1 → 4 → 5
+    //     ↑
+    // 2 → 3
+    let x = vec![[1, 4], [2, 3], [3, 4], [4, 5]];
+    assert_eq!(cut::edge_cut_all(&x), EdgeCut {edges: vec![[4, 5]], verified: true});
+    assert_eq!(cut::node_cut_all(&x), Some(NodeCut {nodes: vec![4], verified: true}));
+    // There is no node between `1` and `2`.
+    assert_eq!(cut::node_cut_all(&[[1, 2]]), None);
+    assert_eq!(cut::node_cut(&[[1, 2], [1, 3], [3, 2]], 1, 2), None);
+}
+
+#[test]
+fn rectangle() {
+    let x = shapes::diag_rect([3, 3]);
+    let r = cut::edge_cut(&x, 0, 8);
+    assert!(r.verified);
+    assert_eq!(r.edges.len(), 2);
+    let r = cut::node_cut(&x, 0, 8).unwrap();
+    assert!(r.verified);
+    assert_eq!(r.nodes.len(), 2);
+}
+
+#[test]
+fn max_flow_min_cut() {
+    // The size of a minimum cut equals the maximum number of disjoint paths.
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..100 {
+        let x = random_maze(&mut rng);
+        for [a, b] in solve(x.clone()) {
+            let r = cut::edge_cut(&x, a, b);
+            assert!(r.verified, "{:?}", x);
+            assert_eq!(r.edges.len(), disjoint::edge_disjoint(&x, a, b).len(), "{:?}", x);
+            if let Some(r) = cut::node_cut(&x, a, b) {
+                assert!(r.verified, "{:?}", x);
+                assert_eq!(r.nodes.len(), disjoint::node_disjoint(&x, a, b).len(), "{:?}", x);
+            }
+        }
+    }
+}
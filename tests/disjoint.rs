@@ -0,0 +1,82 @@
+extern crate reachability_solver;
+
+use std::collections::HashSet;
+
+use reachability_solver::{disjoint, solve, shapes};
+
+/// Xorshift random number generator, to keep the tests free of dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+/// Generates a random maze, which might contain cycles.
+fn random_maze(rng: &mut Rng) -> Vec<[usize; 2]> {
+    let n = 2 + rng.next(6);
+    let m = rng.next(3 * n);
+    (0..m).map(|_| [rng.next(n), rng.next(n)]).collect()
+}
+
+/// Checks that every path goes from `a` to `b` along edges in `x`,
+/// and that no edge, or node when `nodes` is `true`, is shared by two paths.
+fn check(x: &[[usize; 2]], a: usize, b: usize, paths: &[Vec<usize>], nodes: bool) {
+    let mut used_edges = HashSet::new();
+    let mut used_nodes = HashSet::new();
+    for p in paths {
+        assert_eq!(p.first(), Some(&a), "{:?} {:?}", x, p);
+        assert_eq!(p.last(), Some(&b), "{:?} {:?}", x, p);
+        for e in p.windows(2) {
+            assert!(x.contains(&[e[0], e[1]]), "{:?} {:?}", x, p);
+            assert!(used_edges.insert([e[0], e[1]]), "{:?} {:?}", x, paths);
+        }
+        if nodes {
+            for &c in &p[1..p.len() - 1] {
+                assert!(used_nodes.insert(c), "{:?} {:?}", x, paths);
+            }
+        }
+    }
+}
+
+#[test]
+fn module_doc_example() {
+    //     2       5
+    //   ↗︎   ↘︎   ↗︎   ↘︎
+    // 1       4       7
+    //   ↘︎   ↗︎   ↘︎   ↗︎
+    //     3       6
+    let x = vec![[1, 2], [1, 3], [2, 4], [3, 4], [4, 5], [4, 6], [5, 7], [6, 7]];
+    assert_eq!(disjoint::edge_disjoint(&x, 1, 7), vec![vec![1, 2, 4, 5, 7], vec![1, 3, 4, 6, 7]]);
+    // Every path goes through `4`.
+    assert_eq!(disjoint::node_disjoint(&x, 1, 7), vec![vec![1, 2, 4, 5, 7]]);
+}
+
+#[test]
+fn rectangle() {
+    let x = shapes::diag_rect([3, 3]);
+    let r = disjoint::node_disjoint_all(&x);
+    assert_eq!(r.len(), 1);
+    assert_eq!(r[0].0, [0, 8]);
+    assert_eq!(r[0].1.len(), 2);
+    check(&x, 0, 8, &r[0].1, true);
+}
+
+#[test]
+fn random_paths() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..100 {
+        let x = random_maze(&mut rng);
+        for [a, b] in solve(x.clone()) {
+            let (e, n) = (disjoint::edge_disjoint(&x, a, b), disjoint::node_disjoint(&x, a, b));
+            // Since `b` is reachable from `a`, there is at least one path.
+            assert!(!e.is_empty() && !n.is_empty(), "{:?}", x);
+            check(&x, a, b, &e, false);
+            check(&x, a, b, &n, true);
+        }
+    }
+}
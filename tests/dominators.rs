@@ -0,0 +1,55 @@
+extern crate reachability_solver;
+
+use reachability_solver::{dominators, reaches, solve};
+use reachability_solver::dominators::Waypoints;
+
+/// Xorshift random number generator, to keep the tests free of dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+/// Generates a random maze, which might contain cycles.
+fn random_maze(rng: &mut Rng) -> Vec<[usize; 2]> {
+    let n = 2 + rng.next(6);
+    let m = rng.next(3 * n);
+    (0..m).map(|_| [rng.next(n), rng.next(n)]).collect()
+}
+
+#[test]
+fn module_doc_example() {
+    let x = vec![[1, 3], [2, 3], [3, 4], [4, 5]];
+    let mut r = dominators::waypoints(&x);
+    r.sort_by_key(|w| w.pair);
+    assert_eq!(r, vec![
+        Waypoints {pair: [1, 5], nodes: vec![3, 4], edges: vec![[1, 3], [3, 4], [4, 5]]},
+        Waypoints {pair: [2, 5], nodes: vec![3, 4], edges: vec![[2, 3], [3, 4], [4, 5]]},
+    ]);
+}
+
+#[test]
+fn mandatory_edges() {
+    // Compares with removing every edge of the maze, one at a time.
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..100 {
+        let mut x = random_maze(&mut rng);
+        let w = dominators::waypoints(&x);
+        assert_eq!(w.iter().map(|w| w.pair).collect::<Vec<_>>(), solve(x.clone()));
+        x.sort();
+        x.dedup();
+        for w in w {
+            let [a, b] = w.pair;
+            let edges: Vec<[usize; 2]> = x.iter().cloned().filter(|&e| {
+                let rest: Vec<[usize; 2]> = x.iter().cloned().filter(|&f| f != e).collect();
+                !reaches(&rest, a, b)
+            }).collect();
+            assert_eq!(w.edges, edges, "{:?}", x);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+extern crate reachability_solver;
+
+use reachability_solver::{solve, etch, ops};
+
+/// Xorshift random number generator, to keep the tests free of dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+/// Generates a random maze without cycles.
+fn random_dag(rng: &mut Rng) -> Vec<[usize; 2]> {
+    let n = 2 + rng.next(10);
+    // Shuffle node labels, such that edges do not always point to higher numbers.
+    let mut labels: Vec<usize> = (0..n).collect();
+    for i in (1..n).rev() {
+        labels.swap(i, rng.next(i + 1));
+    }
+    let m = rng.next(2 * n);
+    let mut r = vec![];
+    for _ in 0..m {
+        let a = rng.next(n);
+        let b = rng.next(n);
+        if a < b {r.push([labels[a], labels[b]])}
+    }
+    r
+}
+
+fn sorted(mut x: Vec<[usize; 2]>) -> Vec<[usize; 2]> {
+    x.sort();
+    x
+}
+
+/// Runs a check on random mazes without cycles.
+fn for_random_dags(f: fn(&[[usize; 2]])) {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..50 {
+        f(&random_dag(&mut rng));
+    }
+}
+
+#[test]
+fn solve_commutes_with_opposite() {
+    for_random_dags(|x| {
+        let a = solve(x.into());
+        let b = solve(ops::opposite(x));
+        assert_eq!(sorted(b), sorted(ops::opposite(&a)), "{:?}", x);
+    });
+}
+
+#[test]
+fn etch_initial_dual_to_terminal() {
+    for_random_dags(|x| {
+        let y = ops::opposite(x);
+        let mut ex = x.to_vec();
+        etch::initial(&solve(x.into()), &mut ex);
+        let mut ey = y.clone();
+        etch::terminal(&solve(y), &mut ey);
+        assert_eq!(sorted(ex), sorted(ops::opposite(&ey)), "{:?}", x);
+    });
+}
+
+#[test]
+fn cardinality_invariant_under_opposite() {
+    // The cardinality is the same whether initial or terminal nodes are removed.
+    for_random_dags(|x| {
+        assert_eq!(etch::cardinality(x), etch::cardinality(&ops::opposite(x)), "{:?}", x);
+    });
+}
@@ -0,0 +1,61 @@
+extern crate reachability_solver;
+
+use reachability_solver::{try_solve, etch, limits, map, shapes, Error};
+use reachability_solver::limits::{Interrupt, Options};
+
+#[test]
+fn try_solve_ok() {
+    assert_eq!(try_solve(vec![[1, 2], [2, 3]]), Ok(vec![[1, 3]]));
+}
+
+#[test]
+fn try_solve_errors() {
+    assert_eq!(try_solve(vec![]), Err(Error::Empty));
+    assert_eq!(try_solve(vec![[1, 2], [2, 2]]), Err(Error::SelfLoop(2)));
+    assert_eq!(try_solve(vec![[1, 2], [2, 3], [1, 2]]), Err(Error::Duplicate([1, 2])));
+}
+
+#[test]
+fn try_solve_reports_cycles() {
+    // 1 ⇄ 2 → 3 → 4
+    assert_eq!(try_solve(vec![[2, 3], [1, 2], [3, 4], [2, 1]]),
+               Err(Error::CycleOnly(vec![[1, 2], [2, 1]])));
+    // 5 → 1 ⇄ 2    3 ⇄ 4
+    assert_eq!(try_solve(vec![[5, 1], [1, 2], [2, 1], [3, 4], [4, 3]]),
+               Err(Error::CycleOnly(vec![[1, 2], [2, 1], [3, 4], [4, 3]])));
+}
+
+#[test]
+fn try_cardinality() {
+    assert_eq!(etch::try_cardinality(&[]), Ok(0));
+    assert_eq!(etch::try_cardinality(&shapes::diag_rect([3, 3])), Ok(5));
+    assert_eq!(etch::try_cardinality(&[[1, 2], [2, 2]]), Err(Error::SelfLoop(2)));
+    assert_eq!(etch::try_cardinality(&[[1, 2], [1, 2]]), Err(Error::Duplicate([1, 2])));
+    // 1 → 2 → 3 ⇄ 4
+    assert_eq!(etch::try_cardinality(&[[1, 2], [2, 3], [3, 4], [4, 3]]),
+               Err(Error::CycleOnly(vec![[1, 2], [2, 3], [3, 4], [4, 3]])));
+}
+
+#[test]
+fn try_map2() {
+    assert!(map::try_map2([3, 1], &shapes::dir_line(3)).is_ok());
+    assert_eq!(map::try_map2([0, 3], &[]), Err(Error::InvalidDimensions([0, 3])));
+    assert_eq!(map::try_map2([3, 1], &shapes::dir_line(4)),
+               Err(Error::OutOfBounds {node: 3, dim: [3, 1]}));
+    assert_eq!(map::try_map2([3, 3], &[[0, 2]]), Err(Error::NotGrid([0, 2])));
+}
+
+#[test]
+fn interrupted() {
+    let x = shapes::dir_line(3);
+    let options = Options {max_steps: Some(0), ..Options::default()};
+    assert_eq!(limits::solve(x.clone(), options),
+               Err(Error::Interrupted {reason: Interrupt::MaxSteps, partial: x}));
+}
+
+#[test]
+fn display() {
+    assert_eq!(Error::Duplicate([1, 2]).to_string(), "The edge `[1, 2]` occurs more than once");
+    assert_eq!(Error::CycleOnly(vec![[1, 2], [2, 1]]).to_string(),
+               "No initial node reaches a terminal node, because of the edges [[1, 2], [2, 1]]");
+}
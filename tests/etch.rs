@@ -0,0 +1,26 @@
+extern crate reachability_solver;
+
+use reachability_solver::etch;
+
+#[test]
+fn different_mazes_same_signature() {
+    // The shortcut `[1, 3]` can not be detected by initial etching.
+    //
+    // 1 → 2 → 3
+    let a = vec![[1, 2], [2, 3]];
+    // 1 → 2 → 3
+    //  \_____↗
+    let b = vec![[1, 2], [2, 3], [1, 3]];
+    assert_ne!(a, b);
+    assert_eq!(etch::signature(&a), etch::signature(&b));
+}
+
+#[test]
+fn equivalence_classes() {
+    let a = vec![[1, 2], [2, 3]];
+    let b = vec![[1, 2], [2, 3], [1, 3]];
+    // 1 → 2    3
+    let c = vec![[1, 2]];
+    assert_ne!(etch::signature(&a), etch::signature(&c));
+    assert_eq!(etch::equivalence_classes(&[a, b, c]), vec![vec![0, 1], vec![2]]);
+}
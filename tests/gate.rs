@@ -0,0 +1,37 @@
+extern crate reachability_solver;
+
+use reachability_solver::gate::{self, Report, Verdict};
+
+#[test]
+fn shared_gate() {
+    // Harry starts at `1` and Cedric starts at `2`.
+    // Both need the gate `[3, 4]` to reach the Triwizard Cup at `5`.
+    let report = gate::solve(&[[1, 3], [2, 3], [4, 5]], &[[3, 4]], &[[1, 5], [2, 5]]);
+    assert_eq!(report, Report {
+        combinations: vec![vec![0], vec![1]],
+        verdicts: vec![Verdict::DependsOnTiming(vec![1]), Verdict::DependsOnTiming(vec![0])],
+    });
+}
+
+#[test]
+fn bypass() {
+    // Harry can walk around the gate, but might still use it.
+    let report = gate::solve(&[[1, 3], [2, 3], [4, 5], [1, 4]], &[[3, 4]], &[[1, 5], [2, 5]]);
+    assert_eq!(report, Report {
+        combinations: vec![vec![0], vec![0, 1], vec![1]],
+        verdicts: vec![Verdict::Reaches, Verdict::DependsOnTiming(vec![0])],
+    });
+}
+
+#[test]
+fn never() {
+    let report = gate::solve(&[[1, 2]], &[], &[[1, 2], [2, 1]]);
+    assert_eq!(report.combinations, vec![vec![0]]);
+    assert_eq!(report.verdicts, vec![Verdict::Reaches, Verdict::Never]);
+}
+
+#[test]
+#[should_panic(expected = "at most 63 agents")]
+fn too_many_agents() {
+    gate::solve(&[], &[], &[[0, 1]; 64]);
+}
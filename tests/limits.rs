@@ -0,0 +1,60 @@
+extern crate reachability_solver;
+
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::time::Instant;
+
+use reachability_solver::{solve, Error};
+use reachability_solver::limits::{self, Interrupt, Options};
+use reachability_solver::shapes::{dir_line, diag_rect};
+
+fn max_steps(n: usize) -> Options {
+    Options {max_steps: Some(n), ..Options::default()}
+}
+
+#[test]
+fn max_steps_counts_inferences() {
+    // Solving a directed line of 3 takes 4 inference steps.
+    let x = dir_line(3);
+    assert_eq!(limits::solve(x.clone(), max_steps(4)), Ok(solve(x.clone())));
+    assert_eq!(limits::solve(x.clone(), max_steps(5)), Ok(solve(x.clone())));
+    match limits::solve(x, max_steps(3)) {
+        Err(Error::Interrupted {reason: Interrupt::MaxSteps, ..}) => {}
+        r => panic!("{:?}", r),
+    }
+}
+
+
+#[test]
+fn no_limits_same_as_solve() {
+    for x in [
+        dir_line(5),
+        diag_rect([3, 3]),
+        // 1 → 4 → 5
+        //     ↑
+        // 2 → 3
+        vec![[1, 4], [2, 3], [3, 4], [4, 5]],
+        // 1 ⇄ 2 → 3
+        vec![[1, 2], [2, 1], [2, 3]],
+        // 1 → 2 → 3 → 1, 3 → 4
+        vec![[1, 2], [2, 3], [3, 1], [3, 4]],
+    ] {
+        assert_eq!(limits::solve(x.clone(), Options::default()), Ok(solve(x.clone())), "{:?}", x);
+    }
+}
+
+#[test]
+fn cancelled() {
+    let x = diag_rect([3, 3]);
+    let options = Options {cancel: Some(Arc::new(AtomicBool::new(true))), ..Options::default()};
+    assert_eq!(limits::solve(x.clone(), options),
+               Err(Error::Interrupted {reason: Interrupt::Cancelled, partial: x}));
+}
+
+#[test]
+fn deadline() {
+    let x = diag_rect([3, 3]);
+    let options = Options {deadline: Some(Instant::now()), ..Options::default()};
+    assert_eq!(limits::solve(x.clone(), options),
+               Err(Error::Interrupted {reason: Interrupt::Deadline, partial: x}));
+}
@@ -0,0 +1,99 @@
+extern crate reachability_solver;
+
+use reachability_solver::{solve, ops, shapes};
+use reachability_solver::category::Category;
+
+fn sorted(mut x: Vec<[usize; 2]>) -> Vec<[usize; 2]> {
+    x.sort();
+    x.dedup();
+    x
+}
+
+fn mazes() -> Vec<Vec<[usize; 2]>> {
+    vec![
+        shapes::dir_line(3),
+        shapes::diag_rect([2, 3]),
+        // 1 → 2 → 3
+        // ↓   ↓   ↓
+        // 4 → 5 → 6
+        vec![[1, 2], [2, 3], [1, 4], [2, 5], [3, 6], [4, 5], [5, 6]],
+        //   2
+        // 4 5 6
+        //   8
+        vec![[2, 5], [4, 5], [5, 6], [5, 8]],
+    ]
+}
+
+#[test]
+fn solve_opposite() {
+    // The solution of the opposite maze is the opposite of the solution.
+    for a in &mazes() {
+        let x = ops::opposite(a);
+        assert_eq!(sorted(solve(x)), sorted(ops::opposite(&solve(a.clone()))), "{:?}", a);
+    }
+}
+
+#[test]
+fn solve_coproduct() {
+    // The solution of a coproduct is the coproduct of the solutions.
+    for a in &mazes() {
+        for b in &mazes() {
+            let x = ops::coproduct(a, b);
+            let mut y = solve(a.clone());
+            y.extend(ops::shift(&solve(b.clone()), ops::size(a)));
+            assert_eq!(sorted(solve(x)), sorted(y), "{:?} {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn solve_sequence() {
+    // The solution of a sequence is the composition of the solutions,
+    // through the edges that glue the terminal nodes to the initial nodes.
+    for a in &mazes() {
+        for b in &mazes() {
+            let n = ops::size(a);
+            let x = ops::sequence(a, b);
+            let (ca, cb) = (Category::new(a), Category::new(b));
+            let mut glue = vec![];
+            for &t in ca.sinks() {
+                for &i in cb.sources() {
+                    glue.push([t, i + n]);
+                }
+            }
+            let y = ops::compose(
+                &ops::compose(&solve(a.clone()), &glue),
+                &ops::shift(&solve(b.clone()), n)
+            );
+            assert_eq!(sorted(solve(x)), y, "{:?} {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn product_of_lines() {
+    // A rectangle is the product of two lines.
+    for w in 2..5 {
+        for h in 2..5 {
+            let x = ops::product(&shapes::dir_line(w), &shapes::dir_line(h));
+            assert_eq!(sorted(x), sorted(shapes::diag_rect([w, h])));
+        }
+    }
+}
+
+#[test]
+fn solve_product() {
+    // The solution of a product of acyclic mazes pairs the solutions.
+    for a in &mazes() {
+        for b in &mazes() {
+            let w = ops::size(a);
+            let mut y = vec![];
+            for &[x0, x1] in &solve(a.clone()) {
+                for &[y0, y1] in &solve(b.clone()) {
+                    y.push([y0 * w + x0, y1 * w + x1]);
+                }
+            }
+            assert_eq!(sorted(solve(ops::product(a, b))), sorted(y), "{:?} {:?}", a, b);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+extern crate reachability_solver;
+
+use reachability_solver::{ops, parallel, shapes, solve_sorted};
+
+#[test]
+fn components_of_long_line() {
+    let x = shapes::dir_line(300_000);
+    let c = parallel::components(&x);
+    assert_eq!(c.len(), 1);
+    assert_eq!(c[0], x);
+}
+
+#[test]
+fn components_keep_input_order() {
+    let x = vec![[1, 2], [3, 4], [2, 5], [4, 6], [7, 7]];
+    assert_eq!(parallel::components(&x), vec![
+        vec![[1, 2], [2, 5]],
+        vec![[3, 4], [4, 6]],
+        vec![[7, 7]],
+    ]);
+}
+
+#[test]
+fn same_as_solve_sorted() {
+    let mut x = vec![];
+    for n in 2..5 {
+        x = ops::coproduct(&x, &shapes::dir_line(n));
+        x = ops::coproduct(&x, &shapes::diag_rect([n, 2]));
+    }
+    let r = solve_sorted(x.clone());
+    for threads in 1..4 {
+        assert_eq!(parallel::solve_with_threads(&x, threads), r);
+    }
+    assert_eq!(parallel::solve(&x), r);
+}
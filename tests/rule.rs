@@ -0,0 +1,43 @@
+extern crate reachability_solver;
+
+use reachability_solver::{solve, solve_with, shapes};
+use reachability_solver::rule::Transitive;
+
+fn mazes() -> Vec<Vec<[usize; 2]>> {
+    let mut r = vec![];
+    for n in 0..6 {
+        r.push(shapes::dir_line(n));
+    }
+    for w in 1..4 {
+        for h in 1..4 {
+            r.push(shapes::diag_rect([w, h]));
+        }
+    }
+    r.extend(vec![
+        // 1 → 4 → 5
+        //     ↑
+        // 2 → 3
+        vec![[1, 4], [2, 3], [3, 4], [4, 5]],
+        // 0 ⇄ 1
+        vec![[0, 1], [1, 0]],
+        // 1 ⇄ 2 → 3
+        vec![[1, 2], [2, 1], [2, 3]],
+        // 1 → 2 → 3 ⇄ 4
+        vec![[1, 2], [2, 3], [3, 4], [4, 3]],
+        // 1 → 2 → 3 → 1, 3 → 4
+        vec![[1, 2], [2, 3], [3, 1], [3, 4]],
+        // 1 → 1 → 2
+        vec![[1, 1], [1, 2]],
+        // 1 ⇄ 2 → 3 ⇄ 4
+        //  \_______↗
+        vec![[1, 2], [2, 1], [2, 3], [3, 4], [4, 3], [1, 3], [5, 5]],
+    ]);
+    r
+}
+
+#[test]
+fn transitive_same_as_solve() {
+    for x in mazes() {
+        assert_eq!(solve_with(x.clone(), Transitive), solve(x.clone()), "{:?}", x);
+    }
+}
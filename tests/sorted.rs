@@ -0,0 +1,54 @@
+extern crate reachability_solver;
+
+use reachability_solver::{solve_sorted, shapes};
+
+/// Xorshift random number generator, to keep the tests free of dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+/// Shuffles the edges and repeats some of them.
+fn shuffle_and_repeat(rng: &mut Rng, x: &[[usize; 2]]) -> Vec<[usize; 2]> {
+    let mut r: Vec<[usize; 2]> = x.into();
+    for _ in 0..rng.next(x.len() + 1) {
+        let e = r[rng.next(x.len())];
+        r.push(e);
+    }
+    for i in (1..r.len()).rev() {
+        r.swap(i, rng.next(i + 1));
+    }
+    r
+}
+
+#[test]
+fn order_and_repetition() {
+    let mazes = vec![
+        shapes::dir_line(4),
+        shapes::diag_rect([3, 2]),
+        // 1 → 2 → 3
+        // ↓   ↓   ↓
+        // 4 → 5 → 6
+        vec![[1, 2], [2, 3], [1, 4], [2, 5], [3, 6], [4, 5], [5, 6], [7, 6], [1, 8]],
+        // 1 ⇄ 2 → 3
+        vec![[1, 2], [2, 1], [2, 3]],
+        // 0 → 1 → 2 → 3 → 1, 3 → 4
+        vec![[0, 1], [1, 2], [2, 3], [3, 1], [3, 4]],
+        // 1 → 2 → 3 ⇄ 4
+        vec![[1, 2], [2, 3], [3, 4], [4, 3]],
+    ];
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for x in &mazes {
+        let expected = solve_sorted(x.clone());
+        for _ in 0..10 {
+            let y = shuffle_and_repeat(&mut rng, x);
+            assert_eq!(solve_sorted(y.clone()), expected, "{:?} {:?}", x, y);
+        }
+    }
+}
@@ -0,0 +1,23 @@
+extern crate reachability_solver;
+
+use reachability_solver::{solve, Error};
+use reachability_solver::stepper::Stepper;
+
+#[test]
+fn visualize_cycle_while_propagating() {
+    // 0 ⇄ 1 → 2
+    let x = vec![[0, 1], [1, 0], [1, 2]];
+    let mut s = Stepper::new(x.clone());
+    loop {
+        assert!(s.visualize([3, 1]).is_ok(), "{:?}", s.current_facts());
+        if s.step().is_none() {break}
+    }
+    assert_eq!(s.current_facts(), &solve(x)[..]);
+}
+
+#[test]
+fn visualize_errors() {
+    let s = Stepper::new(vec![[0, 1], [1, 3]]);
+    assert_eq!(s.visualize([0, 2]), Err(Error::InvalidDimensions([0, 2])));
+    assert_eq!(s.visualize([3, 1]), Err(Error::OutOfBounds {node: 3, dim: [3, 1]}));
+}
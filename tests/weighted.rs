@@ -0,0 +1,26 @@
+extern crate reachability_solver;
+
+use reachability_solver::weighted::{self, Distance};
+
+#[test]
+fn harry_and_cedric() {
+    // Harry starts at `1` and Cedric starts at `2`.
+    let (pairs, r) = weighted::solve(&[[1, 4], [2, 3], [3, 4], [4, 5]], &[3, 1, 1, 2]);
+    assert_eq!(pairs, vec![[1, 5], [2, 5]]);
+    assert_eq!(r, vec![
+        Distance {pair: [1, 5], distance: 5, hops: 2},
+        Distance {pair: [2, 5], distance: 4, hops: 3},
+    ]);
+}
+
+#[test]
+fn saturating_distance() {
+    let (_, r) = weighted::solve(&[[1, 2], [2, 3]], &[u64::MAX, 1]);
+    assert_eq!(r, vec![Distance {pair: [1, 3], distance: u64::MAX, hops: 2}]);
+}
+
+#[test]
+#[should_panic(expected = "Expected one weight per edge")]
+fn missing_weight() {
+    weighted::solve(&[[1, 2], [2, 3]], &[1]);
+}